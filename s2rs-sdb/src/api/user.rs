@@ -1,5 +1,6 @@
 use s2rs_derive::Forwarder;
 use serde::Deserialize;
+use s2rs::Country;
 use super::{Api, utils::ResponseUtils};
 
 #[derive(Deserialize, Debug, Clone)]
@@ -9,7 +10,7 @@ pub struct UserMeta {
     #[serde( rename = "username" )]
     pub name: String,
     pub joined: String,
-    pub country: String,
+    pub country: Country,
     pub bio: String,
     pub work: String,
     pub status: UserStatus,
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 use derivative::Derivative;
 use s2rs_derive::deref;
+use s2rs::Country;
 use crate::api::{Api, self};
 
 // region: UserMeta
@@ -9,7 +10,7 @@ pub struct UserMeta {
     pub this: Arc<UserWithId>,
     pub sys_id: u64,
     pub joined: String,
-    pub country: String,
+    pub country: Country,
     pub bio: String,
     pub work: String,
     pub status: api::UserStatus,
@@ -0,0 +1,311 @@
+use std::{collections::{HashMap, VecDeque}, path::PathBuf, sync::{Arc, Mutex}, time::{Duration, SystemTime, UNIX_EPOCH}};
+use reqwest::{Method, StatusCode, header::{self, HeaderMap}};
+use serde::{Deserialize, Serialize};
+use derivative::Derivative;
+use super::{Api, protocols, domains};
+
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or_default()
+}
+
+// region: CacheKey
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CacheKey {
+    pub method: String,
+    pub url: String,
+}
+
+impl CacheKey {
+    pub fn new(method: &Method, url: impl Into<String>) -> Self {
+        Self {
+            method: method.to_string(),
+            url: url.into()
+        }
+    }
+
+    /// Stable FNV-1a hash of the key, used for on-disk file names
+    pub fn hash_hex(&self) -> String {
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for byte in self.method.bytes().chain([b' ']).chain(self.url.bytes()) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        format!["{hash:016x}"]
+    }
+}
+// endregion: CacheKey
+
+// region: CacheEntry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub body: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    /// Unix time in milliseconds
+    pub stored_at: u64,
+}
+
+impl CacheEntry {
+    fn new(body: String, headers: &HeaderMap) -> Self {
+        let header = |name| headers.get(name).and_then(|v: &header::HeaderValue| v.to_str().ok()).map(ToOwned::to_owned);
+        Self {
+            etag: header(header::ETAG),
+            last_modified: header(header::LAST_MODIFIED),
+            stored_at: now(),
+            body,
+        }
+    }
+
+    pub fn is_fresh(&self, ttl: Duration) -> bool {
+        now().saturating_sub(self.stored_at) < ttl.as_millis() as u64
+    }
+}
+// endregion: CacheEntry
+
+// region: CacheBackend
+/// Storage used by [`Cache`]
+/// - Implement it to keep responses somewhere else (redis, sqlite, ...)
+pub trait CacheBackend: Send + Sync {
+    fn get(&self, key: &CacheKey) -> Option<CacheEntry>;
+    fn set(&self, key: CacheKey, entry: CacheEntry);
+    fn remove(&self, key: &CacheKey);
+    fn clear(&self);
+}
+
+/// In-memory least recently used cache
+#[derive(Debug)]
+pub struct MemoryCache {
+    capacity: usize,
+    items: Mutex<(HashMap<CacheKey, CacheEntry>, VecDeque<CacheKey>)>,
+}
+
+impl MemoryCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            items: Mutex::default()
+        }
+    }
+}
+
+impl CacheBackend for MemoryCache {
+    fn get(&self, key: &CacheKey) -> Option<CacheEntry> {
+        let mut items = self.items.lock().ok()?;
+        let (entries, order) = &mut *items;
+        let entry = entries.get(key)?.clone();
+        order.retain(|k| k != key);
+        order.push_back(key.clone());
+        Some(entry)
+    }
+
+    fn set(&self, key: CacheKey, entry: CacheEntry) {
+        if let Ok(mut items) = self.items.lock() {
+            let (entries, order) = &mut *items;
+            order.retain(|k| k != &key);
+            order.push_back(key.clone());
+            entries.insert(key, entry);
+            while entries.len() > self.capacity {
+                match order.pop_front() {
+                    Some(oldest) => { entries.remove(&oldest); },
+                    None => break
+                }
+            }
+        }
+    }
+
+    fn remove(&self, key: &CacheKey) {
+        if let Ok(mut items) = self.items.lock() {
+            let (entries, order) = &mut *items;
+            entries.remove(key);
+            order.retain(|k| k != key);
+        }
+    }
+
+    fn clear(&self) {
+        if let Ok(mut items) = self.items.lock() {
+            items.0.clear();
+            items.1.clear();
+        }
+    }
+}
+
+/// Stores every response as a JSON file inside `dir`
+/// - IO errors are treated as cache misses
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    pub dir: PathBuf,
+}
+
+impl DiskCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into()
+        }
+    }
+
+    fn path(&self, key: &CacheKey) -> PathBuf {
+        self.dir.join(format!["{}.json", key.hash_hex()])
+    }
+}
+
+impl CacheBackend for DiskCache {
+    fn get(&self, key: &CacheKey) -> Option<CacheEntry> {
+        let (stored_key, entry): (CacheKey, CacheEntry) = serde_json::from_slice(&std::fs::read(self.path(key)).ok()?).ok()?;
+        (&stored_key == key).then_some(entry)
+    }
+
+    fn set(&self, key: CacheKey, entry: CacheEntry) {
+        if let Ok(data) = serde_json::to_vec(&(&key, entry)) {
+            let _ = std::fs::create_dir_all(&self.dir);
+            let _ = std::fs::write(self.path(&key), data);
+        }
+    }
+
+    fn remove(&self, key: &CacheKey) {
+        let _ = std::fs::remove_file(self.path(key));
+    }
+
+    fn clear(&self) {
+        if let Ok(entries) = std::fs::read_dir(&self.dir) {
+            for entry in entries.flatten() {
+                if entry.path().extension().is_some_and(|ext| ext == "json") {
+                    let _ = std::fs::remove_file(entry.path());
+                }
+            }
+        }
+    }
+}
+// endregion: CacheBackend
+
+// region: Cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CacheEndpoint {
+    ProjectMeta,
+    UserMeta,
+    StudioMeta,
+}
+
+/// Opt-in response cache for [`Api`]
+/// - Fresh entries are returned without any request
+/// - Stale entries are revalidated with `If-None-Match` / `If-Modified-Since` when server sent `ETag` / `Last-Modified`
+/// # Examples
+/// ```
+/// use std::{sync::Arc, time::Duration};
+/// use s2rs::api::{Api, Cache, CacheEndpoint};
+/// let cache = Cache::memory(1000, Duration::from_secs(60))
+/// .with_endpoint_ttl(CacheEndpoint::UserMeta, Duration::from_secs(600));
+/// let api = Api::new("YourUsername").with_cache(Arc::new(cache));
+/// ```
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Cache {
+    #[derivative(Debug = "ignore")]
+    backend: Arc<dyn CacheBackend>,
+    ttl: Duration,
+    endpoint_ttls: HashMap<CacheEndpoint, Duration>,
+}
+
+impl Cache {
+    pub fn new(backend: Arc<dyn CacheBackend>, ttl: Duration) -> Self {
+        Self {
+            backend,
+            ttl,
+            endpoint_ttls: HashMap::new()
+        }
+    }
+
+    pub fn memory(capacity: usize, ttl: Duration) -> Self {
+        Self::new(Arc::new(MemoryCache::new(capacity)), ttl)
+    }
+
+    pub fn disk(dir: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Self::new(Arc::new(DiskCache::new(dir)), ttl)
+    }
+
+    pub fn with_endpoint_ttl(mut self, endpoint: CacheEndpoint, ttl: Duration) -> Self {
+        self.endpoint_ttls.insert(endpoint, ttl);
+        self
+    }
+
+    pub fn ttl(&self, endpoint: CacheEndpoint) -> Duration {
+        self.endpoint_ttls.get(&endpoint).copied().unwrap_or(self.ttl)
+    }
+
+    pub fn invalidate(&self, key: &CacheKey) {
+        self.backend.remove(key)
+    }
+
+    pub fn clear(&self) {
+        self.backend.clear()
+    }
+}
+// endregion: Cache
+
+impl Api {
+    /// Same api with responses of cacheable endpoints going through `cache`
+    pub fn with_cache(self: &Arc<Self>, cache: Arc<Cache>) -> Arc<Self> {
+        Arc::new(Self {
+            client: self.client.clone(),
+            name: self.name.clone(),
+            headers: self.headers.clone(),
+            cache: Some(cache),
+            in_flight: self.in_flight.clone(),
+        })
+    }
+
+    pub fn cache(&self) -> Option<&Arc<Cache>> {
+        self.cache.as_ref()
+    }
+
+    fn cache_key(path: &str) -> CacheKey {
+        CacheKey::new(&Method::GET, format!["{}{}{path}", protocols::HTTPS, domains::API])
+    }
+
+    /// Removes cached response of api `path`
+    pub fn invalidate_cached(&self, path: &str) {
+        if let Some(cache) = &self.cache {
+            cache.invalidate(&Self::cache_key(path));
+        }
+    }
+
+    /// GET request to api `path` going through cache
+    /// - `refresh` skips cached entry, but still stores the new response
+    /// - Concurrent identical requests share a single in-flight request
+    pub(super) async fn get_cached(&self, endpoint: CacheEndpoint, path: &str, refresh: bool) -> super::Result<String> {
+        let key = Self::cache_key(path);
+        let cache = self.cache.as_ref();
+        let cached = cache.filter(|_| !refresh).and_then(|cache| cache.backend.get(&key));
+
+        let mut request = self.get(path);
+        if let (Some(cache), Some(entry)) = (cache, &cached) {
+            if entry.is_fresh(cache.ttl(endpoint)) {
+                return Ok(entry.body.clone())
+            }
+            if let Some(etag) = &entry.etag {
+                request = request.header(header::IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &entry.last_modified {
+                request = request.header(header::IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let flight_key = format!["{} {}{}", key.method, key.url, if cached.is_some() { " revalidate" } else { "" }];
+        let response = self.in_flight.send(flight_key, request).await?;
+
+        if response.status == StatusCode::NOT_MODIFIED {
+            if let (Some(cache), Some(mut entry)) = (cache, cached) {
+                entry.stored_at = now();
+                cache.backend.set(key, entry.clone());
+                return Ok(entry.body)
+            }
+        }
+        if !response.status.is_success() {
+            Err(response.status)?
+        }
+
+        if let Some(cache) = cache {
+            cache.backend.set(key, CacheEntry::new(response.body.clone(), &response.headers));
+        }
+        Ok(response.body.clone())
+    }
+}
@@ -4,11 +4,17 @@ use super::{Api, utils::RequestBuilderUtils};
 use crate::json::{self, Parsable};
 use crate::cursor::Cursor;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CloudAction {
     pub by_name: String,
     pub event: CloudActionEvent,
+    /// Unix time in milliseconds
     pub timestamp: u64,
+    /// `timestamp` converted to a date
+    /// - `Requires crate feature: 'time'`
+    #[cfg(feature = "time")]
+    pub date: crate::Date,
 }
 
 #[derive(Clone, Debug, Forwarder)]
@@ -20,15 +26,19 @@ pub enum CloudActionParseError {
 impl json::Parsable for CloudAction {
     type Error = CloudActionParseError;
     fn parse(data: &json::Parser) -> Result<Self, Self::Error> {
+        let timestamp = data.i("timestamp").u64()?;
         Ok(Self {
             by_name: data.i("user").string()?,
             event: data.parse()?,
-            timestamp: data.i("timestamp").u64()?,
+            timestamp,
+            #[cfg(feature = "time")]
+            date: crate::Date::from_millis(timestamp),
         })
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum CloudActionEvent {
     Create(String),
     Delete(String),
@@ -65,7 +75,7 @@ impl json::Parsable for CloudActionEvent {
 
 #[derive(Forwarder, Debug)]
 pub enum GetProjectCloudActivityError {
-    #[forward(StatusCode, reqwest::Error)]
+    #[forward(StatusCode, reqwest::Error, serde_json::Error)]
     This(super::Error),
     #[forward] Parsing(CloudActionParseError),
 }
@@ -73,7 +83,7 @@ pub enum GetProjectCloudActivityError {
 impl Api {
     pub async fn project_cloud_activity(&self, id: u64, cursor: impl Into<Cursor>) -> Result<Vec<CloudAction>, GetProjectCloudActivityError> {
         let response = self.get_cloud("logs").cursor_limited(cursor, 100)
-        .query(&[("projectid", id)]).send_success().await?;
-        Ok(CloudAction::parse_vec(&response.json::<Vec<_>>().await?)?)
+        .query(&[("projectid", id)]).send_shared_success(&self.in_flight).await?;
+        Ok(CloudAction::parse_vec(&response.json::<Vec<_>>()?)?)
     }
 }
\ No newline at end of file
@@ -1,8 +1,10 @@
 use serde::{Deserialize, Serialize};
 use super::{Api, utils::RequestBuilderUtils};
 use crate::cursor::Cursor;
+use crate::date::Date;
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Comment {
     pub id: u64,
     pub author: CommentAuthor,
@@ -11,13 +13,14 @@ pub struct Comment {
     pub to_user_id: Option<u64>,
     pub content: String,
     #[serde( rename = "datetime_created" )]
-    pub created_at: String,
+    pub created_at: Date,
     #[serde( rename = "datetime_modified" )]
-    pub modified_at: String,
+    pub modified_at: Date,
     pub reply_count: u64,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CommentAuthor {
     pub id: u64,
     #[serde( rename = "username" )]
@@ -54,22 +57,34 @@ impl From<&str> for SendComment {
 
 impl Api {
     pub async fn user_project_comments(&self, name: &str, id: u64, cursor: impl Into<Cursor>) -> super::Result<Vec<Comment>> {
-        let response = self.get(&format!("users/{name}/projects/{id}/comments/")).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+        let response = self.get(&format!("users/{name}/projects/{id}/comments/")).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
     pub async fn user_project_comment_replies(&self, name: &str, id: u64, comment_id: u64, cursor: impl Into<Cursor>) -> super::Result<Vec<Comment>> {
-        let response = self.get(&format!("users/{name}/projects/{id}/comments/{comment_id}/replies/")).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+        let response = self.get(&format!("users/{name}/projects/{id}/comments/{comment_id}/replies/")).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
     pub async fn studio_comments(&self, id: u64, cursor: impl Into<Cursor>) -> super::Result<Vec<Comment>> {
-        let response = self.get(&format!("studios/{id}/comments/")).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+        let response = self.get(&format!("studios/{id}/comments/")).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
     pub async fn studio_comment_replies(&self, id: u64, comment_id: u64, cursor: impl Into<Cursor>) -> super::Result<Vec<Comment>> {
-        let response = self.get(&format!("studios/{id}/comments/{comment_id}/")).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+        let response = self.get(&format!("studios/{id}/comments/{comment_id}/replies/")).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
+    }
+
+    /// Replies have `parent_id` of their thread
+    pub async fn user_project_comment(&self, name: &str, id: u64, comment_id: u64) -> super::Result<Comment> {
+        let response = self.get(&format!("users/{name}/projects/{id}/comments/{comment_id}/")).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
+    }
+
+    /// Replies have `parent_id` of their thread
+    pub async fn studio_comment(&self, id: u64, comment_id: u64) -> super::Result<Comment> {
+        let response = self.get(&format!("studios/{id}/comments/{comment_id}/")).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 }
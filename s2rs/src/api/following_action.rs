@@ -1,15 +1,16 @@
 use s2rs_derive::Forwarder;
-use super::utils::ResponseUtils;
 use super::{Api, utils::RequestBuilderUtils};
 use crate::json;
 use crate::cursor::Cursor;
+use crate::date::{self, Date};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FollowingAction {
     pub id: u64,
     pub actor_name: String,
     pub actor_id: u64,
-    pub created_at: String,
+    pub created_at: Date,
     pub event: FollowingActionEvent,
     pub event_type: String,
 }
@@ -26,7 +27,7 @@ impl json::Parsable for FollowingAction {
         Ok(FollowingAction {
             actor_name: data.i("actor_username").string()?,
             actor_id: data.i("actor_id").u64()?,
-            created_at: data.i("datetime_created").string()?,
+            created_at: date::parse(data.i("datetime_created").string()?),
             event: data.parse()?,
             id: data.i("id").u64()?,
             event_type: data.i("type").string()?
@@ -34,7 +35,8 @@ impl json::Parsable for FollowingAction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum FollowingActionEvent {
     FollowUser {
         to_name: String,
@@ -72,12 +74,16 @@ pub enum FollowingActionEvent {
         title: String,
         to_name: String,
         to_id: u64,
+    },
+    /// Type not modelled yet, `raw` is the whole action
+    Unknown {
+        event_type: String,
+        raw: serde_json::Value,
     }
 }
 
 #[derive(Forwarder, Clone, Debug)]
 pub enum FollowingActionEventParseError {
-    InvalidEventType(String),
     #[forward] Expected(json::ExpectedError)
 }
 
@@ -122,7 +128,10 @@ impl json::Parsable for FollowingActionEvent {
                 to_name: data.i("recipient_username").string()?,
                 title: data.i("gallery_title").string()?,
             },
-            event_type => Err(FollowingActionEventParseError::InvalidEventType(event_type.to_owned()))?
+            event_type => Self::Unknown {
+                event_type: event_type.to_owned(),
+                raw: data.value().clone()
+            }
         })
     }
 }
@@ -130,13 +139,13 @@ impl json::Parsable for FollowingActionEvent {
 #[derive(Forwarder)]
 pub enum GetFollowingUsersActivityError {
     #[forward] Parsing(FollowingActionParseError),
-    #[forward(reqwest::Error)]
+    #[forward(reqwest::Error, serde_json::Error)]
     This(super::Error)
 }
 
 impl Api {
     pub async fn following_users_activity(&self, name: &str, cursor: impl Into<Cursor>) -> Result<Vec<FollowingAction>, GetFollowingUsersActivityError> {
-        let response = self.get(&format!["users/{name}/following/users/activity/"]).cursor(cursor).send_success().await?;
-        response.json_parser_vec().await
+        let response = self.get(&format!["users/{name}/following/users/activity/"]).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        response.json_parser_vec()
     }
 }
\ No newline at end of file
@@ -4,8 +4,14 @@ use super::utils::RequestBuilderUtils;
 #[cfg(feature = "time")] use chrono::{DateTime, Utc};
 use s2rs_derive::Forwarder;
 use serde_json::json;
+use crate::date::Date;
+#[cfg(feature = "html")] use html_parser::{Dom, Element, Node};
+#[cfg(feature = "html")] use reqwest::StatusCode;
+#[cfg(feature = "html")] use crate::html::{ElementUtils, DomUtils};
 
 // region: ForumCategory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ForumCategory {
     Announcements,
     NewScratchers,
@@ -26,13 +32,99 @@ pub enum ForumCategory {
     ThingsIAmReadingAndPlaying,
 }
 
+impl ForumCategory {
+    pub const ALL: [Self; 17] = [
+        Self::Announcements,
+        Self::NewScratchers,
+        Self::HelpWithScripts,
+        Self::ShowAndTell,
+        Self::ProjectIdeas,
+        Self::Collaboration,
+        Self::Requests,
+        Self::ProjectSaveLevelCodes,
+        Self::QuestionsAboutScratch,
+        Self::Suggestions,
+        Self::BugsAndGlitches,
+        Self::AdvancedTopics,
+        Self::ConnectingToThePhysicalWorld,
+        Self::DevelopingScratchExtensions,
+        Self::OpenSourceProjects,
+        Self::ThingsIAmMakingAndCreating,
+        Self::ThingsIAmReadingAndPlaying,
+    ];
+
+    /// Id of the category in `scratch.mit.edu/discuss/{id}/`
+    pub fn id(&self) -> u16 {
+        match self {
+            Self::Announcements => 5,
+            Self::NewScratchers => 6,
+            Self::HelpWithScripts => 7,
+            Self::ShowAndTell => 8,
+            Self::ProjectIdeas => 9,
+            Self::Collaboration => 10,
+            Self::Requests => 11,
+            Self::ProjectSaveLevelCodes => 60,
+            Self::QuestionsAboutScratch => 4,
+            Self::Suggestions => 1,
+            Self::BugsAndGlitches => 3,
+            Self::AdvancedTopics => 31,
+            Self::ConnectingToThePhysicalWorld => 32,
+            Self::DevelopingScratchExtensions => 48,
+            Self::OpenSourceProjects => 49,
+            Self::ThingsIAmMakingAndCreating => 29,
+            Self::ThingsIAmReadingAndPlaying => 30,
+        }
+    }
+
+    pub fn from_id(id: u16) -> Option<Self> {
+        Self::ALL.into_iter().find(|category| category.id() == id)
+    }
+
+    /// Title of the category as shown on the forums
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Announcements => "Announcements",
+            Self::NewScratchers => "New Scratchers",
+            Self::HelpWithScripts => "Help with Scripts",
+            Self::ShowAndTell => "Show and Tell",
+            Self::ProjectIdeas => "Project Ideas",
+            Self::Collaboration => "Collaboration",
+            Self::Requests => "Requests",
+            Self::ProjectSaveLevelCodes => "Project Save & Level Codes",
+            Self::QuestionsAboutScratch => "Questions about Scratch",
+            Self::Suggestions => "Suggestions",
+            Self::BugsAndGlitches => "Bugs and Glitches",
+            Self::AdvancedTopics => "Advanced Topics",
+            Self::ConnectingToThePhysicalWorld => "Connecting to the Physical World",
+            Self::DevelopingScratchExtensions => "Developing Scratch Extensions",
+            Self::OpenSourceProjects => "Open Source Projects",
+            Self::ThingsIAmMakingAndCreating => "Things I'm Making and Creating",
+            Self::ThingsIAmReadingAndPlaying => "Things I'm Reading and Playing",
+        }
+    }
+}
+
+/// Name of the category used by ScratchDB
 impl AsRef<str> for ForumCategory {
     fn as_ref(&self) -> &str {
         match self {
-            Self::AdvancedTopics => "AdvancedTopics",
             Self::Announcements => "Announcements",
+            Self::NewScratchers => "NewScratchers",
+            Self::HelpWithScripts => "HelpWithScripts",
+            Self::ShowAndTell => "ShowAndTell",
+            Self::ProjectIdeas => "ProjectIdeas",
+            Self::Collaboration => "Collaboration",
+            Self::Requests => "Requests",
+            Self::ProjectSaveLevelCodes => "ProjectSaveLevelCodes",
+            Self::QuestionsAboutScratch => "QuestionsAboutScratch",
+            Self::Suggestions => "Suggestions",
             Self::BugsAndGlitches => "BugsAndGlitches",
-            _ => todo!()
+            Self::AdvancedTopics => "AdvancedTopics",
+            Self::ConnectingToThePhysicalWorld => "ConnectingToThePhysicalWorld",
+            Self::DevelopingScratchExtensions => "DevelopingScratchExtensions",
+            Self::OpenSourceProjects => "OpenSourceProjects",
+            Self::ThingsIAmMakingAndCreating => "ThingsIAmMakingAndCreating",
+            Self::ThingsIAmReadingAndPlaying => "ThingsIAmReadingAndPlaying",
         }
     }
 }
@@ -40,6 +132,8 @@ impl AsRef<str> for ForumCategory {
 
 // region: ForumTopicRss
 #[cfg(feature = "time")]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ForumTopicRss {
     pub title: String,
     pub id: u64,
@@ -85,6 +179,8 @@ impl ForumTopicRss {
 
 // region: ForumTopicRssPost
 #[cfg(feature = "time")]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ForumTopicRssPost {
     pub id: u64,
     pub created_at: DateTime<Utc>,
@@ -117,6 +213,182 @@ impl ForumTopicRssPost {
 }
 // endregion: ForumTopicRssPost
 
+// region: ParseForumError
+/// Single topic or post of a forum page couldn't be parsed
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ParseForumError {
+    /// `None` when the id itself couldn't be read
+    pub id: Option<u64>,
+    /// Part of the markup which is missing or malformed
+    pub part: &'static str,
+}
+// endregion: ParseForumError
+
+// region: ForumCategoryTopic
+/// Topic as listed on a page of [`ForumCategory`]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ForumCategoryTopic {
+    pub id: u64,
+    pub title: String,
+    pub author_name: String,
+    pub reply_count: u64,
+    pub view_count: u64,
+    pub sticky: bool,
+    pub closed: bool,
+    pub last_post_id: Option<u64>,
+    pub last_post_at: Date,
+}
+
+#[cfg(feature = "html")]
+impl ForumCategoryTopic {
+    fn from_html(row: &Element) -> Result<Self, ParseForumError> {
+        let columns: Vec<_> = row.children.iter().filter_map(|node| node.element()).filter(|element| element.name == "td").collect();
+        let link = columns.first().and_then(|column| column.descendants().into_iter().find(|element| element.name == "a"));
+        let id = link.and_then(|link| id_from_href(&link.get_attribute("href")?));
+        let error = |part| ParseForumError { id, part };
+
+        let [info, replies, views, last_post] = columns[..] else {
+            Err(error("columns"))?
+        };
+        let info_elements = info.descendants();
+        let has_class = |class: &str| info_elements.iter().any(|element| element.classes.iter().any(|name| name == class));
+        let last_post = last_post.descendants().into_iter().find(|element| element.name == "a").ok_or(error("last_post"))?;
+
+        Ok(Self {
+            id: id.ok_or(error("id"))?,
+            title: link.ok_or(error("title"))?.text_content().trim().to_owned(),
+            author_name: info_elements.iter().find(|element| element.classes.iter().any(|class| class == "byuser"))
+            .map(|author| author.text_content()).as_deref().map(str::trim)
+            .map(|author| author.strip_prefix("by").unwrap_or(author).trim().to_owned())
+            .ok_or(error("author_name"))?,
+            reply_count: parse_count(replies).ok_or(error("reply_count"))?,
+            view_count: parse_count(views).ok_or(error("view_count"))?,
+            sticky: has_class("sticky") || row.classes.iter().any(|class| class == "isticky"),
+            closed: has_class("closed"),
+            last_post_id: last_post.get_attribute("href").and_then(|href| id_from_href(&href)),
+            last_post_at: crate::date::parse(last_post.text_content().trim().to_owned()),
+        })
+    }
+}
+// endregion: ForumCategoryTopic
+
+// region: ForumTopicPost
+/// Post as shown on a page of a forum topic
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ForumTopicPost {
+    pub id: u64,
+    pub topic_id: u64,
+    /// Position of the post in the topic, starting at 1
+    pub index: Option<u32>,
+    pub author_name: String,
+    /// Rank shown under the avatar, such as `Scratcher` or `Scratch Team`
+    pub author_rank: Option<String>,
+    /// Post count shown under the avatar, Scratch rounds it down (e.g. `1000+ posts`)
+    pub author_post_count: Option<u64>,
+    pub author_avatar_url: Option<String>,
+    pub created_at: Date,
+    /// Rendered post as plain text, use [`Api::forum_post_content`] to get the source
+    pub content: String,
+    pub signature: Option<String>,
+    pub edit: Option<ForumPostEdit>,
+}
+
+/// "Last edited by" note of a post
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ForumPostEdit {
+    pub author_name: String,
+    pub edited_at: Date,
+}
+
+#[cfg(feature = "html")]
+impl ForumTopicPost {
+    fn from_html(element: &Element, topic_id: u64) -> Result<Self, ParseForumError> {
+        let id = element.id.as_deref().and_then(|id| id.strip_prefix('p')?.parse().ok());
+        let error = |part| ParseForumError { id, part };
+        let elements = element.descendants();
+        let by_class = |class: &str| elements.iter().find(|element| element.classes.iter().any(|name| name == class)).copied();
+
+        let author_name = by_class("username").ok_or(error("author_name"))?.text_content().trim().to_owned();
+        let author_info = by_class("postleft").map(text_lines).unwrap_or_default();
+        let author_post_count = author_info.iter().find(|line| line.ends_with("posts") || line.ends_with("post"));
+
+        Ok(Self {
+            id: id.ok_or(error("id"))?,
+            topic_id,
+            index: by_class("conr").and_then(|index| index.text_content().trim().strip_prefix('#')?.parse().ok()),
+            author_rank: author_info.iter().find(|line| **line != author_name && Some(*line) != author_post_count).cloned(),
+            author_post_count: author_post_count.and_then(|count| count.split(['+', ' ']).next()?.parse().ok()),
+            author_avatar_url: by_class("postavatar").and_then(|avatar| {
+                avatar.descendants().into_iter().find(|element| element.name == "img")?.get_attribute("src")
+            }),
+            author_name,
+            created_at: crate::date::parse(
+                by_class("box-head").and_then(|head| head.child_by_name("a")).ok_or(error("created_at"))?.text_content().trim().to_owned()
+            ),
+            content: by_class("post_body_html").ok_or(error("content"))?.text_content().trim().to_owned(),
+            signature: by_class("postsignature").map(|signature| signature.text_content().trim().to_owned())
+            .filter(|signature| !signature.is_empty()),
+            edit: by_class("posteditmessage").and_then(|edit| ForumPostEdit::from_message(edit.text_content().trim())),
+        })
+    }
+}
+
+#[cfg(feature = "html")]
+impl ForumPostEdit {
+    /// Parses `Last edited by {name} ({date})`
+    fn from_message(message: &str) -> Option<Self> {
+        let (author_name, edited_at) = message.strip_prefix("Last edited by")?.trim().split_once(" (")?;
+        Some(Self {
+            author_name: author_name.trim().to_owned(),
+            edited_at: crate::date::parse(edited_at.trim_end_matches(')').to_owned()),
+        })
+    }
+}
+
+/// Non-empty lines of text inside of `element`
+#[cfg(feature = "html")]
+fn text_lines(element: &Element) -> Vec<String> {
+    let mut result = Vec::new();
+    for node in &element.children {
+        match node {
+            Node::Text(text) => result.extend(
+                crate::html::decode_entities(text).lines().map(str::trim).filter(|line| !line.is_empty()).map(str::to_owned)
+            ),
+            Node::Element(element) => result.append(&mut text_lines(element)),
+            Node::Comment(_) => {}
+        }
+    }
+    result
+}
+// endregion: ForumTopicPost
+
+/// Reads the id out of links such as `/discuss/topic/{id}/`
+#[cfg(feature = "html")]
+fn id_from_href(href: &str) -> Option<u64> {
+    href.trim_end_matches('/').rsplit('/').next()?.parse().ok()
+}
+
+#[cfg(feature = "html")]
+fn parse_count(element: &Element) -> Option<u64> {
+    element.text_content().trim().replace(',', "").parse().ok()
+}
+
+#[derive(Debug, Forwarder)]
+pub enum GetForumPageError {
+    Parsing,
+    #[forward(reqwest::StatusCode, reqwest::Error)]
+    This(super::Error)
+}
+
+/// Amount of topics on one page of a forum category
+pub const FORUM_CATEGORY_PAGE_LIMIT: usize = 25;
+/// Amount of posts on one page of a forum topic
+pub const FORUM_TOPIC_PAGE_LIMIT: usize = 20;
+
 #[cfg(feature = "rss")]
 #[derive(Forwarder, Debug)]
 pub enum GetForumTopicRssError {
@@ -128,14 +400,55 @@ pub enum GetForumTopicRssError {
 
 impl Api {
     pub async fn forum_post_content(&self, id: u64) -> super::Result<String> {
-        let response = self.get_base(&format!["discuss/post/{id}/source/"]).send_success().await?;
-        Ok(response.text().await?)
+        let response = self.get_base(&format!["discuss/post/{id}/source/"]).send_shared_success(&self.in_flight).await?;
+        Ok(response.body.clone())
+    }
+
+    /// One page of topics in `category`, `page` starts at 1
+    /// - Pages past the last one are returned as empty
+    /// - Topics which can't be parsed are returned as errors in their place
+    #[cfg(feature = "html")]
+    pub async fn forum_category_topics(&self, category: ForumCategory, page: Option<u32>) -> Result<Vec<Result<ForumCategoryTopic, ParseForumError>>, GetForumPageError> {
+        let Some(dom) = self.forum_page(&format!["discuss/{}/", category.id()], page).await? else {
+            return Ok(Vec::new())
+        };
+        Ok(dom.descendants().into_iter()
+        .filter(|element| element.name == "tbody")
+        .flat_map(|body| body.children.iter().filter_map(|node| node.element()))
+        .filter(|row| row.name == "tr")
+        .map(ForumCategoryTopic::from_html)
+        .collect())
+    }
+
+    /// One page of posts in topic `id`, `page` starts at 1
+    /// - Pages past the last one are returned as empty
+    /// - Posts which can't be parsed are returned as errors in their place
+    #[cfg(feature = "html")]
+    pub async fn forum_topic_posts(&self, id: u64, page: Option<u32>) -> Result<Vec<Result<ForumTopicPost, ParseForumError>>, GetForumPageError> {
+        let Some(dom) = self.forum_page(&format!["discuss/topic/{id}/"], page).await? else {
+            return Ok(Vec::new())
+        };
+        Ok(dom.descendants().into_iter()
+        .filter(|element| element.classes.iter().any(|class| class == "blockpost"))
+        .map(|element| ForumTopicPost::from_html(element, id))
+        .collect())
+    }
+
+    /// `None` when the page doesn't exist
+    #[cfg(feature = "html")]
+    async fn forum_page(&self, path: &str, page: Option<u32>) -> Result<Option<Dom>, GetForumPageError> {
+        let response = self.get_base(path).query(&[("page", page)]).send_shared(&self.in_flight).await?;
+        if response.status == StatusCode::NOT_FOUND {
+            return Ok(None)
+        }
+        let data = response.only_success()?.body.clone();
+        Ok(Some(Dom::parse(&data).ok().ok_or(GetForumPageError::Parsing)?))
     }
 
     #[cfg(feature = "rss")]
     pub async fn forum_topic_rss(&self, id: u64) -> Result<ForumTopicRss, GetForumTopicRssError> {
-        let response = self.get_base(&format!["discuss/feeds/topic/{id}/"]).send_success().await?;
-        let feed = feed_rs::parser::parse(response.text().await?.as_bytes())?;
+        let response = self.get_base(&format!["discuss/feeds/topic/{id}/"]).send_shared_success(&self.in_flight).await?;
+        let feed = feed_rs::parser::parse(response.body.as_bytes())?;
         Ok(ForumTopicRss::try_from_rss(feed)?)
     }
 
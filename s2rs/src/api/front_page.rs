@@ -5,6 +5,7 @@ use super::utils::RequestBuilderUtils;
 use crate::json;
 
 #[derive(Debug, Deserialize, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct News {
     pub id: u64,
     #[serde( rename = "stamp" )]
@@ -19,6 +20,7 @@ pub struct News {
 
 // region: FrontPage
 #[derive(Debug, Deserialize, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FrontPage {
     #[serde( rename = "community_newest_projects" )]
     pub new_projects: Vec<FrontPageProject>,
@@ -37,6 +39,7 @@ pub struct FrontPage {
 }
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FrontPageProject {
     pub thumbnail_url: String,
     pub title: String,
@@ -47,6 +50,7 @@ pub struct FrontPageProject {
 }
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FrontPageMostRemixedProject {
     pub id: u64,
     pub title: String,
@@ -59,6 +63,7 @@ pub struct FrontPageMostRemixedProject {
 }
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FrontPageDesignStudioProject {
     pub id: u64,
     #[serde( rename = "gallery_id" )]
@@ -75,6 +80,7 @@ pub struct FrontPageDesignStudioProject {
 }
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FrontPageCuratedProject {
     pub id: u64,
     pub title: String,
@@ -87,6 +93,7 @@ pub struct FrontPageCuratedProject {
 }
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FrontPageFeaturedStudio {
     pub id: u64,
     pub title: String,
@@ -96,6 +103,7 @@ pub struct FrontPageFeaturedStudio {
 
 // region: Health
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Health {
     pub version: String,
     pub uptime: f32,
@@ -106,12 +114,14 @@ pub struct Health {
 }
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct HealthCache {
     pub connected: bool,
     pub ready: bool
 }
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct HealthSql {
     pub main: HealthSqlItem,
     pub project_comments: HealthSqlItem,
@@ -122,12 +132,14 @@ pub struct HealthSql {
 }
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct HealthSqlItem {
     pub primary: HealthSqlItemItem,
     pub replica: HealthSqlItemItem
 }
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct HealthSqlItemItem {
     pub ssl: bool,
     pub destroyed: bool,
@@ -147,24 +159,24 @@ pub struct HealthSqlItemItem {
 #[derive(Forwarder, Debug)]
 pub enum GetProjectsCountError {
     #[forward] Expected(json::ExpectedError),
-    #[forward(reqwest::Error)]
+    #[forward(reqwest::Error, serde_json::Error)]
     This(super::Error)
 }
 
 impl Api {
     pub async fn front_page(&self) -> super::Result<FrontPage> {
-        let response = self.get_proxy("featured/").send_success().await?;
-        Ok(response.json().await?)
+        let response = self.get_proxy("featured/").send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
     pub async fn news(&self) -> super::Result<Vec<News>> {
-        let response = self.get("news").send_success().await?;
-        Ok(response.json().await?)
+        let response = self.get("news").send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
     pub async fn projects_count(&self) -> Result<u64, GetProjectsCountError> {
-        let response = self.get("projects/count/all/").send_success().await?;
-        let data: json::Parser = response.json().await?;
+        let response = self.get("projects/count/all/").send_shared_success(&self.in_flight).await?;
+        let data: json::Parser = response.json()?;
         Ok(data.i("count").u64()?)
     }
 
@@ -174,7 +186,7 @@ impl Api {
     }
 
     pub async fn health(&self) -> super::Result<Health> {
-        let response = self.get("health/").send_success().await?;
-        Ok(response.json().await?)
+        let response = self.get("health/").send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 }
\ No newline at end of file
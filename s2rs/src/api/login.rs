@@ -4,6 +4,7 @@ use crate::json;
 use super::Api;
 
 #[derive(Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct LoginResponse {
     #[serde( rename = "username" )]
     pub name: String,
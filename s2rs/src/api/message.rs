@@ -1,13 +1,15 @@
 use s2rs_derive::Forwarder;
-use super::utils::ResponseUtils;
 use super::{Api, utils::RequestBuilderUtils};
 use crate::cursor::Cursor;
 use crate::json;
+use crate::date::{self, Date};
 
 // region: Message
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Message {
     pub id: u64,
-    pub created_at: String,
+    pub created_at: Date,
     pub actor_name: String,
     pub actor_id: u64,
     pub event: MessageEvent,
@@ -25,7 +27,7 @@ impl json::Parsable for Message {
     fn parse(data: &json::Parser) -> Result<Self, Self::Error> {
         Ok(Self {
             id: data.i("id").u64()?,
-            created_at: data.i("datetime_created").string()?,
+            created_at: date::parse(data.i("datetime_created").string()?),
             actor_name: data.i("actor_username").string()?,
             actor_id: data.i("actor_id").u64()?,
             event: data.parse()?,
@@ -36,6 +38,8 @@ impl json::Parsable for Message {
 // endregion: Message
 
 // region: MessageCommentLocation
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum MessageCommentLocation {
     Profile,
     Project,
@@ -55,6 +59,8 @@ impl MessageCommentLocation {
 // endregion: MessageCommentLocation
 
 // region: MessageEvent
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum MessageEvent {
     FollowUser {
         to_id: u64,
@@ -99,14 +105,22 @@ pub enum MessageEvent {
         id: u64,
         title: String,
     },
-    Welcome
+    PromoteHostStudio {
+        id: u64,
+        title: String,
+    },
+    Welcome,
+    /// Type not modelled yet, `raw` is the whole message
+    Unknown {
+        event_type: String,
+        raw: serde_json::Value,
+    }
 }
 
 #[derive(Forwarder, Debug, Clone)]
 pub enum MessageEventParseError {
     #[forward] Expected(json::ExpectedError),
     #[forward] CommentLocation(u8),
-    InvalidType(String)
 }
 
 impl json::Parsable for MessageEvent {
@@ -159,7 +173,15 @@ impl json::Parsable for MessageEvent {
                 id: data.i("topic_id").u64()?,
                 title: data.i("topic_title").string()?,
             },
-            t => Err(MessageEventParseError::InvalidType(t.to_owned()))?
+            "becomehoststudio" => Self::PromoteHostStudio {
+                id: data.i("gallery_id").u64()?,
+                title: data.i("gallery_title").string()?,
+            },
+            "userjoin" => Self::Welcome,
+            t => Self::Unknown {
+                event_type: t.to_owned(),
+                raw: data.value().clone()
+            }
         })
     }
 }
@@ -167,14 +189,14 @@ impl json::Parsable for MessageEvent {
 
 #[derive(Forwarder, Debug)]
 pub enum GetUserMessagesError {
-    #[forward(reqwest::Error)]
+    #[forward(reqwest::Error, serde_json::Error)]
     This(super::Error),
     #[forward] Parsing(MessageParseError)
 }
 
 impl Api {
     pub async fn user_messages(&self, name: &str, cursor: impl Into<Cursor>) -> Result<Vec<Message>, GetUserMessagesError> {
-        let response = self.get(&format!["users/{name}/messages/"]).cursor(cursor).send_success().await?;
-        response.json_parser_vec().await
+        let response = self.get(&format!["users/{name}/messages/"]).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        response.json_parser_vec()
     }
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 use reqwest::{StatusCode, Client, Method, RequestBuilder};
 use s2rs_derive::Forwarder;
+use derivative::Derivative;
 use crate::{cookies::Cookies, headers, utils::into_arc::IntoArc};
 
 pub use studio::*;
@@ -15,11 +16,11 @@ pub use user_comment::*;
 pub use cloud_action::*;
 pub use user_featured::*;
 pub use front_page::*;
-pub use explore::*;
 pub use search::*;
 pub use forum::*;
 pub use login::*;
 pub use stuff::*;
+pub use cache::*;
 
 pub mod user;
 pub mod project;
@@ -36,10 +37,11 @@ pub mod cloud;
 pub mod forum;
 pub mod user_featured;
 pub mod front_page;
-pub mod explore;
 pub mod search;
 pub mod login;
 pub mod stuff;
+pub mod cache;
+mod single_flight;
 mod utils;
 
 pub mod protocols {
@@ -84,7 +86,7 @@ pub enum HeadersError {
     #[forward] Reqwest(headers::TryIntoReqwestHeadersError),
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct Headers {
     pub reqwest: reqwest::header::HeaderMap,
     pub local: Arc<headers::Headers>
@@ -111,11 +113,16 @@ pub trait Extension {
     fn extended(pipe: ExtensionPipe, this: Arc<Api>) -> Arc<Self>;
 }
 
-#[derive(Debug)]
+#[derive(Derivative)]
+#[derivative(Debug)]
 pub struct Api {
     client: Arc<Client>,
     name: Arc<String>,
     headers: Headers,
+    #[derivative(Debug = "ignore")]
+    cache: Option<Arc<Cache>>,
+    #[derivative(Debug = "ignore")]
+    in_flight: Arc<single_flight::SingleFlight>,
 }
 
 impl Api {
@@ -131,7 +138,9 @@ impl Api {
         Arc::new(Self {
             client: Arc::new(Client::new()),
             name: name.into_arc(),
-            headers: Headers::default()
+            headers: Headers::default(),
+            cache: None,
+            in_flight: Default::default(),
         })
     }
 
@@ -158,6 +167,8 @@ impl Api {
             client: Arc::new(Client::new()),
             name: name.into(),
             headers: Arc::new(headers).try_into()?,
+            cache: None,
+            in_flight: Default::default(),
         }))
     }
 
@@ -214,6 +225,9 @@ impl Api {
     fn post_site_api(&self, path: &str) -> RequestBuilder {
         self.request_site_api(Method::POST, path)
     }
+    fn delete_site_api(&self, path: &str) -> RequestBuilder {
+        self.request_site_api(Method::DELETE, path)
+    }
     // endregion: site-api
 
     // region: proxy
@@ -253,6 +267,18 @@ impl Api {
     }
     // endregion: uploads
 
+    // region: projects
+    fn request_projects(&self, method: Method, path: &str) -> RequestBuilder {
+        self.https_request(method, &format!["{}{path}", domains::PROJECTS])
+    }
+    fn get_projects(&self, path: &str) -> RequestBuilder {
+        self.request_projects(Method::GET, path)
+    }
+    fn post_projects(&self, path: &str) -> RequestBuilder {
+        self.request_projects(Method::POST, path)
+    }
+    // endregion: projects
+
     // region: internal_api
     fn request_internal_api(&self, method: Method, path: &str) -> RequestBuilder {
         self.https_request(method, &format!["{}internalapi/{path}", domains::BASE])
@@ -1,46 +1,16 @@
-use super::{Api, user::{UserProfileImages, UserHistory}, utils::RequestBuilderUtils, SendComment};
+use super::{Api, user::{UserProfileImages, UserHistory}, utils::RequestBuilderUtils, SendComment, CacheEndpoint};
+use s2rs_derive::Forwarder;
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use crate::cursor::Cursor;
+use crate::date::Date;
 
 // region: Project
-#[derive(Deserialize, Debug)]
-pub struct Project2 {
-    pub id: u64,
-    pub title: String,
-    pub description: String,
-    pub instructions: String,
-    pub visibility: String,
-    pub public: bool,
-    pub comments_allowed: bool,
-    pub is_published: bool,
-    pub author: ProjectAuthor,
-    pub image: String,
-    pub images: ProjectImages,
-    pub stats: ProjectStats,
-    pub remix: ProjectRemix,
-    pub history: ProjectHistory,
-}
-
-#[derive(Deserialize, Debug)]
-pub struct Project3 {
-    pub id: u64,
-    pub title: String,
-    pub description: String,
-    pub instructions: String, 
-    pub visibility: String,
-    pub public: bool,
-    pub comments_allowed: bool,
-    pub is_published: bool,
-    pub author: Project3Author,
-    pub image: String,
-    pub images: ProjectImages,
-    pub stats: ProjectStats,
-    pub remix: ProjectRemix,
-    pub history: ProjectHistory,
-}
-
-#[derive(Deserialize, Debug)]
+/// Project as returned by meta and listing endpoints
+/// - `token` is only present in [`Api::project_meta`]
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Project {
     pub id: u64,
     pub title: String,
@@ -56,33 +26,27 @@ pub struct Project {
     pub stats: ProjectStats,
     pub remix: ProjectRemix,
     pub history: ProjectHistory,
-    #[serde( rename = "project_token" )]
-    pub token: String
+    #[serde( rename = "project_token", default )]
+    pub token: Option<String>
 }
 // endregion: Project
 
 // region: ProjectAuthor
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ProjectAuthor {
     pub id: u64,
-    #[serde( rename = "username" )]
-    pub name: String,
-    #[serde( rename = "scratchteam" )]
-    pub scratch_team: bool,
-    pub history: UserHistory,
-    pub profile: ProjectAuthorProfile,
-}
-
-#[derive(Deserialize, Debug)]
-pub struct Project3Author {
-    pub id: u64,
+    /// Missing in some listings, e.g. user projects and remixes
+    #[serde( rename = "username", default )]
+    pub name: Option<String>,
     #[serde( rename = "scratchteam" )]
     pub scratch_team: bool,
     pub history: UserHistory,
     pub profile: ProjectAuthorProfile,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ProjectAuthorProfile {
     pub id: (),
     pub images: UserProfileImages,
@@ -90,7 +54,8 @@ pub struct ProjectAuthorProfile {
 // endregion: ProjectAuthor
 
 // region: Project extra
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ProjectImages {
     #[serde( rename = "282x218" )]
     pub x282_218: String,
@@ -106,7 +71,8 @@ pub struct ProjectImages {
     pub x100_80: String,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ProjectStats {
     pub views: u64,
     pub loves: u64,
@@ -114,14 +80,16 @@ pub struct ProjectStats {
     pub remixes: u64,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ProjectHistory {
-    pub created: String,
-    pub modified: String,
-    pub shared: String,
+    pub created: Date,
+    pub modified: Date,
+    pub shared: Date,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ProjectRemix {
     pub parent: Option<u64>,
     pub root: Option<u64>,
@@ -141,38 +109,48 @@ pub struct ProjectInfo {
 
 impl Api {
     pub async fn project_meta(&self, id: u64) -> super::Result<Project> {
-        let response = self.get(&format!("projects/{id}/")).send_success().await?;
-        Ok(response.json().await?)
+        let data = self.get_cached(CacheEndpoint::ProjectMeta, &format!("projects/{id}/"), false).await?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    /// Same as [`Api::project_meta`], but skips cached response
+    pub async fn refresh_project_meta(&self, id: u64) -> super::Result<Project> {
+        let data = self.get_cached(CacheEndpoint::ProjectMeta, &format!("projects/{id}/"), true).await?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    pub fn invalidate_project_meta(&self, id: u64) {
+        self.invalidate_cached(&format!("projects/{id}/"))
     }
 
-    pub async fn user_projects(&self, name: &str, cursor: impl Into<Cursor>) -> super::Result<Vec<Project3>> {
-        let response = self.get(&format!("users/{name}/projects/")).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+    pub async fn user_projects(&self, name: &str, cursor: impl Into<Cursor>) -> super::Result<Vec<Project>> {
+        let response = self.get(&format!("users/{name}/projects/")).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
-    pub async fn user_favorites(&self, name: &str, cursor: impl Into<Cursor>) -> super::Result<Vec<Project3>> {
-        let response = self.get(&format!("users/{name}/favorites/")).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+    pub async fn user_favorites(&self, name: &str, cursor: impl Into<Cursor>) -> super::Result<Vec<Project>> {
+        let response = self.get(&format!("users/{name}/favorites/")).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
-    pub async fn viewed_projects(&self, cursor: impl Into<Cursor>) -> super::Result<Vec<Project2>> {
-        let response = self.get(&format!("users/{}/projects/recentlyviewed/", &self.name)).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+    pub async fn viewed_projects(&self, cursor: impl Into<Cursor>) -> super::Result<Vec<Project>> {
+        let response = self.get(&format!("users/{}/projects/recentlyviewed/", &self.name)).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
-    pub async fn projects_loved_by_following(&self, cursor: impl Into<Cursor>) -> super::Result<Vec<Project2>> {
-        let response = self.get(&format!["users/{}/following/users/loves/", &self.name]).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+    pub async fn projects_loved_by_following(&self, cursor: impl Into<Cursor>) -> super::Result<Vec<Project>> {
+        let response = self.get(&format!["users/{}/following/users/loves/", &self.name]).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
-    pub async fn projects_shared_by_following(&self, cursor: impl Into<Cursor>) -> super::Result<Vec<Project2>> {
-        let response = self.get(&format!["users/{}/following/users/projects/", &self.name]).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+    pub async fn projects_shared_by_following(&self, cursor: impl Into<Cursor>) -> super::Result<Vec<Project>> {
+        let response = self.get(&format!["users/{}/following/users/projects/", &self.name]).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
-    pub async fn project_remixes(&self, id: u64, cursor: impl Into<Cursor>) -> super::Result<Vec<Project3>> {
-        let response = self.get(&format!("projects/{id}/remixes/")).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+    pub async fn project_remixes(&self, id: u64, cursor: impl Into<Cursor>) -> super::Result<Vec<Project>> {
+        let response = self.get(&format!("projects/{id}/remixes/")).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
     pub async fn send_project_comment(&self, id: u64, data: &SendComment) -> super::Result<()> {
@@ -216,6 +194,36 @@ impl Api {
         Ok(())
     }
 
+    pub async fn share_project(&self, id: u64) -> super::Result<()> {
+        let _ = self.put_proxy(&format!("projects/{id}/share/")).project_send_success(id).await?;
+        Ok(())
+    }
+
+    fn set_project_visibility(&self, id: u64, visibility: &str) -> reqwest::RequestBuilder {
+        self.put_site_api(&format!("projects/all/{id}/")).json(&json!({
+            "id": id,
+            "visibility": visibility,
+        }))
+    }
+
+    /// Moves project to trash, it can be restored with [`Api::restore_project`]
+    pub async fn trash_project(&self, id: u64) -> super::Result<()> {
+        let _ = self.set_project_visibility(id, "trshbyusr").send_success().await?;
+        Ok(())
+    }
+
+    /// Moves project out of trash
+    pub async fn restore_project(&self, id: u64) -> super::Result<()> {
+        let _ = self.set_project_visibility(id, "visible").send_success().await?;
+        Ok(())
+    }
+
+    /// Permanently deletes project, only works for projects in trash
+    pub async fn delete_project(&self, id: u64) -> super::Result<()> {
+        let _ = self.delete_site_api(&format!("projects/trashed/{id}/")).send_success().await?;
+        Ok(())
+    }
+
     pub async fn view_project(&self, id: u64) -> super::Result<()> {
         let _ = self.post(&format!("users/{}/projects/{id}/views", self.name())).project_send_success(id).await?;
         Ok(())
@@ -247,4 +255,49 @@ impl Api {
         let _ = self.post_internal_api(&format!["project/thumbnail/{id}/set/"]).body(buffer).send_success().await?;
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+// region: CreateProject
+#[derive(Deserialize)]
+struct CreatedProject {
+    #[serde( rename = "content-name" )]
+    id: String,
+}
+
+#[derive(Debug, Forwarder)]
+pub enum CreateProjectError {
+    #[forward] InvalidId(std::num::ParseIntError),
+    #[forward(StatusCode, reqwest::Error, serde_json::Error)]
+    This(super::Error),
+}
+
+impl Api {
+    /// Project's `project.json`
+    /// - `token` from [`Api::project_meta`] is required for projects which aren't shared
+    pub async fn project_json(&self, id: u64, token: Option<&str>) -> super::Result<serde_json::Value> {
+        let response = self.get_projects(&format!["{id}"]).query(&[("token", token)]).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
+    }
+
+    async fn create_project(&self, id: u64, kind: &str, title: &str, data: &serde_json::Value) -> Result<u64, CreateProjectError> {
+        let response = self.post_projects("")
+        .query(&[(kind, "1"), ("title", title)])
+        .query(&[("original_id", id)])
+        .json(data)
+        .project_send_success(id).await?;
+        let created: CreatedProject = response.json().await?;
+        Ok(created.id.parse()?)
+    }
+
+    /// Creates remix of project `id` with `data` as its `project.json`, returns id of the remix
+    /// - Assets are shared between projects by their md5 hash, so only `project.json` has to be sent
+    pub async fn remix_project(&self, id: u64, title: &str, data: &serde_json::Value) -> Result<u64, CreateProjectError> {
+        self.create_project(id, "is_remix", title, data).await
+    }
+
+    /// Same as [`Api::remix_project`], but the new project isn't marked as remix
+    pub async fn copy_project(&self, id: u64, title: &str, data: &serde_json::Value) -> Result<u64, CreateProjectError> {
+        self.create_project(id, "is_copy", title, data).await
+    }
+}
+// endregion: CreateProject
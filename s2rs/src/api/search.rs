@@ -1,35 +1,129 @@
-use crate::{Api, cursor::Cursor};
-use super::{ExploreMode, Project2, utils::RequestBuilderUtils, Studio2};
+use crate::{Api, cursor::Cursor, Language};
+use super::{Project, utils::RequestBuilderUtils, Studio};
 
-pub type SearchMode = ExploreMode;
+// region: SearchMode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchMode {
+    Popular,
+    Trending,
+    Recent,
+}
+
+impl AsRef<str> for SearchMode {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Popular => "popular",
+            Self::Trending => "trending",
+            Self::Recent => "recent",
+        }
+    }
+}
+// endregion: SearchMode
+
+// region: ExploreCategory
+/// Category of the explore page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExploreCategory {
+    All,
+    Animations,
+    Art,
+    Games,
+    Music,
+    Stories,
+    Tutorials,
+}
 
-#[derive(Clone, Debug)]
+impl AsRef<str> for ExploreCategory {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::All => "*",
+            Self::Animations => "animations",
+            Self::Art => "art",
+            Self::Games => "games",
+            Self::Music => "music",
+            Self::Stories => "stories",
+            Self::Tutorials => "tutorials",
+        }
+    }
+}
+// endregion: ExploreCategory
+
+// region: SearchQuery
+/// Search by text or browse explore category
+/// - When both `query` and `category` are set, `query` is used, Scratch can't filter search results by category
+/// # Examples
+/// ```
+/// use s2rs::{api::{SearchQuery, SearchMode, ExploreCategory}, Language};
+/// let query = SearchQuery::new("platformer").with_mode(SearchMode::Recent).with_language(Language::English);
+/// let games = SearchQuery::category(ExploreCategory::Games).with_mode(SearchMode::Trending);
+/// ```
+#[derive(Clone, Debug, Default)]
 pub struct SearchQuery {
-    pub mode: Option<SearchMode>,
     pub query: Option<String>,
+    pub category: Option<ExploreCategory>,
+    pub mode: Option<SearchMode>,
+    pub language: Option<Language>,
 }
 
 impl SearchQuery {
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: Some(query.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn category(category: ExploreCategory) -> Self {
+        Self {
+            category: Some(category),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_mode(mut self, mode: SearchMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    pub fn with_language(mut self, language: Language) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// Endpoint the query goes to, `kind` is either `projects` or `studios`
+    fn path(&self, kind: &str) -> String {
+        match (&self.query, &self.category) {
+            (None, Some(_)) => format!["explore/{kind}/"],
+            _ => format!["search/{kind}/"]
+        }
+    }
+
     pub fn as_query(&self) -> Vec<(&str, &str)> {
         let mut result = Vec::new();
         if let Some(query) = &self.query {
             result.push(("q", query.as_str()))
+        } else if let Some(category) = &self.category {
+            result.push(("q", category.as_ref()))
         }
         if let Some(mode) = &self.mode {
             result.push(("mode", mode.as_ref()))
         }
+        if let Some(language) = &self.language {
+            result.push(("language", language.as_code()))
+        }
         result
     }
 }
+// endregion: SearchQuery
 
 impl Api {
-    pub async fn search_projects(&self, query: &SearchQuery, cursor: impl Into<Cursor>) -> super::Result<Vec<Project2>> {
-        let response = self.get("search/projects/").query(&query.as_query()).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+    pub async fn search_projects(&self, query: &SearchQuery, cursor: impl Into<Cursor>) -> super::Result<Vec<Project>> {
+        let response = self.get(&query.path("projects")).query(&query.as_query()).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
-    pub async fn search_studios(&self, query: &SearchQuery, cursor: impl Into<Cursor>) -> super::Result<Vec<Studio2>> {
-        let response = self.get("search/studios/").query(&query.as_query()).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+    pub async fn search_studios(&self, query: &SearchQuery, cursor: impl Into<Cursor>) -> super::Result<Vec<Studio>> {
+        let response = self.get(&query.path("studios")).query(&query.as_query()).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
-}
\ No newline at end of file
+}
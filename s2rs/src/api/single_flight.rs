@@ -0,0 +1,78 @@
+use std::{collections::HashMap, sync::{Arc, Mutex}};
+use futures_util::{FutureExt, future::{BoxFuture, Shared}};
+use reqwest::{RequestBuilder, StatusCode, header::HeaderMap};
+use serde::de::DeserializeOwned;
+use crate::json;
+
+#[derive(Debug)]
+pub struct SharedResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+impl SharedResponse {
+    pub async fn fetch(request: RequestBuilder) -> Result<Self, reqwest::Error> {
+        let response = request.send().await?;
+        Ok(Self {
+            status: response.status(),
+            headers: response.headers().clone(),
+            body: response.text().await?,
+        })
+    }
+
+    pub fn only_success(self: Arc<Self>) -> Result<Arc<Self>, StatusCode> {
+        if self.status.is_success() {
+            Ok(self)
+        } else {
+            Err(self.status)
+        }
+    }
+
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_str(&self.body)
+    }
+
+    pub fn json_parser_vec<T: json::Parsable, E: From<T::Error> + From<serde_json::Error>>(&self) -> Result<Vec<T>, E> {
+        Ok(T::parse_vec(&self.json::<Vec<json::Parser>>()?)?)
+    }
+}
+
+type SharedFuture = Shared<BoxFuture<'static, Option<Arc<SharedResponse>>>>;
+
+/// Coalesces concurrent identical requests into a single one
+/// - Used for every GET with a text body, see [`super::utils::RequestBuilderUtils::send_shared`]
+/// - Every caller waiting on the same key gets the same response
+/// - Network errors are not shared, each caller retries on its own to get a proper error
+#[derive(Default)]
+pub struct SingleFlight {
+    calls: Mutex<HashMap<String, SharedFuture>>,
+}
+
+impl SingleFlight {
+    pub async fn send(&self, key: String, request: RequestBuilder) -> Result<Arc<SharedResponse>, reqwest::Error> {
+        let Some(shared_request) = request.try_clone() else {
+            return Ok(Arc::new(SharedResponse::fetch(request).await?))
+        };
+
+        let future = {
+            let mut calls = self.calls.lock().unwrap_or_else(|e| e.into_inner());
+            calls.entry(key.clone()).or_insert_with(||
+                SharedResponse::fetch(shared_request).map(|result| result.ok().map(Arc::new)).boxed().shared()
+            ).clone()
+        };
+        let result = future.clone().await;
+
+        {
+            let mut calls = self.calls.lock().unwrap_or_else(|e| e.into_inner());
+            if calls.get(&key).is_some_and(|call| call.ptr_eq(&future)) {
+                calls.remove(&key);
+            }
+        }
+
+        match result {
+            Some(response) => Ok(response),
+            None => Ok(Arc::new(SharedResponse::fetch(request).await?))
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
-use super::{Api, utils::RequestBuilderUtils, SendComment};
+use super::{Api, utils::RequestBuilderUtils, SendComment, CacheEndpoint};
 use crate::cursor::Cursor;
+use crate::date::Date;
 
-#[derive(Deserialize, Debug)]
+/// Studio as returned by meta and listing endpoints
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Studio {
     pub id: u64,
     pub title: String,
@@ -14,30 +17,20 @@ pub struct Studio {
     pub comments_allowed: bool,
     pub image: String,
     pub history: StudioHistory,
-    pub stats: StudioStats
+    /// Missing in some listings, e.g. curated studios and search
+    #[serde( default )]
+    pub stats: Option<StudioStats>
 }
 
-#[derive(Deserialize, Debug)]
-pub struct Studio2 {
-    pub id: u64,
-    pub title: String,
-    pub host: u64,
-    pub description: String,
-    pub visibility: String,
-    pub public: bool,
-    pub open_to_all: bool,
-    pub comments_allowed: bool,
-    pub image: String,
-    pub history: StudioHistory,
-}
-
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StudioHistory {
-    pub created: String,
-    pub modified: String,
+    pub created: Date,
+    pub modified: Date,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StudioStats {
     pub comments: u64,
     pub followers: u64,
@@ -45,12 +38,13 @@ pub struct StudioStats {
     pub projects: u64,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct AddStudioProject {
     #[serde( rename = "actorId" )]
     pub actor_id: u64,
     #[serde( rename = "datetimeCreated" )]
-    pub created_at: String,
+    pub created_at: Date,
     #[serde( rename = "projectId", deserialize_with = "crate::utils::serde::de::string_to_u64" )]
     pub project_id: u64,
     #[serde( rename = "studioId", deserialize_with = "crate::utils::serde::de::string_to_u64" )]
@@ -67,18 +61,28 @@ pub struct StudioInfo {
 
 impl Api {
     pub async fn studio_meta(&self, id: u64) -> super::Result<Studio> {
-        let response = self.get(&format!["studios/{id}/"]).send_success().await?;
-        Ok(response.json().await?)
+        let data = self.get_cached(CacheEndpoint::StudioMeta, &format!["studios/{id}/"], false).await?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    /// Same as [`Api::studio_meta`], but skips cached response
+    pub async fn refresh_studio_meta(&self, id: u64) -> super::Result<Studio> {
+        let data = self.get_cached(CacheEndpoint::StudioMeta, &format!["studios/{id}/"], true).await?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    pub fn invalidate_studio_meta(&self, id: u64) {
+        self.invalidate_cached(&format!["studios/{id}/"])
     }
 
-    pub async fn user_curating_studios(&self, name: &str, cursor: impl Into<Cursor>) -> super::Result<Vec<Studio2>> {
-        let response = self.get(&format!["users/{name}/studios/curate/"]).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+    pub async fn user_curating_studios(&self, name: &str, cursor: impl Into<Cursor>) -> super::Result<Vec<Studio>> {
+        let response = self.get(&format!["users/{name}/studios/curate/"]).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
     pub async fn project_studios(&self, id: u64, cursor: impl Into<Cursor>) -> super::Result<Vec<Studio>> {
-        let response = self.get(&format!["projects/{id}/studios/"]).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+        let response = self.get(&format!["projects/{id}/studios/"]).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
     pub async fn add_studio_project(&self, id: u64, project_id: u64) -> super::Result<()> {
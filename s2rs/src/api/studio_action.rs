@@ -1,16 +1,17 @@
 use std::str::FromStr;
 use s2rs_derive::Forwarder;
-use super::utils::ResponseUtils;
 use super::{Api, utils::RequestBuilderUtils};
 use crate::cursor::Cursor;
 use crate::json;
+use crate::date::{self, Date};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StudioAction {
     pub id: u64,
     pub actor_name: String,
     pub actor_id: u64,
-    pub created_at: String,
+    pub created_at: Date,
     pub event: StudioActionEvent,
     pub event_type: String,
 }
@@ -38,7 +39,7 @@ impl json::Parsable for StudioAction {
         Ok(StudioAction {
             actor_name: data.i("actor_username").string()?,
             actor_id: data.i("actor_id").u64()?,
-            created_at: data.i("datetime_created").string()?,
+            created_at: date::parse(data.i("datetime_created").string()?),
             event: data.parse()?,
             id,
             event_type: data.i("type").string()?
@@ -46,7 +47,8 @@ impl json::Parsable for StudioAction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum StudioActionEvent {
     Update,
     AddProject {
@@ -63,12 +65,22 @@ pub enum StudioActionEvent {
     Promote {
         name: String // recipient_username
     },
+    PromoteHost {
+        name: String // recipient_username
+    },
+    RemoveCurator {
+        name: String // username
+    },
+    /// Type not modelled yet, `raw` is the whole action
+    Unknown {
+        event_type: String,
+        raw: serde_json::Value,
+    }
 }
 
 #[derive(Forwarder, Clone, Debug)]
 pub enum StudioActionEventParseError {
     #[forward] Expected(json::ExpectedError),
-    InvalidType(String)
 }
 
 impl json::Parsable for StudioActionEvent {
@@ -90,20 +102,29 @@ impl json::Parsable for StudioActionEvent {
             "becomeownerstudio" => Self::Promote {
                 name: data.i("recipient_username").string()?
             },
-            t => Err(StudioActionEventParseError::InvalidType(t.to_owned()))?
+            "becomehoststudio" => Self::PromoteHost {
+                name: data.i("recipient_username").string()?
+            },
+            "removecuratorstudio" => Self::RemoveCurator {
+                name: data.i("username").string()?
+            },
+            t => Self::Unknown {
+                event_type: t.to_owned(),
+                raw: data.value().clone()
+            }
         })
     }
 }
 
 #[derive(Forwarder, Debug)]
 pub enum GetStudioActivityError {
-    #[forward(reqwest::Error)] This(super::Error),
+    #[forward(reqwest::Error, serde_json::Error)] This(super::Error),
     #[forward] Parsing(StudioActionParseError),
 }
 
 impl Api {
     pub async fn studio_activity(&self, id: u64, cursor: impl Into<Cursor>) -> Result<Vec<StudioAction>, GetStudioActivityError> {
-        let response = self.get(&format!["studios/{id}/activity/"]).cursor(cursor).send_success().await?;
-        response.json_parser_vec().await
+        let response = self.get(&format!["studios/{id}/activity/"]).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        response.json_parser_vec()
     }
 }
\ No newline at end of file
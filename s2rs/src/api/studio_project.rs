@@ -2,7 +2,8 @@ use serde::Deserialize;
 use super::{user::UserProfileImages, Api, utils::RequestBuilderUtils};
 use crate::cursor::Cursor;
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StudioProject {
     pub id: u64,
     pub title: String,
@@ -16,7 +17,7 @@ pub struct StudioProject {
 
 impl Api {
     pub async fn studio_projects(&self, id: u64, cursor: impl Into<Cursor>) -> super::Result<Vec<StudioProject>> {
-        let response = self.get(&format!["studios/{id}/projects"]).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+        let response = self.get(&format!["studios/{id}/projects"]).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 }
\ No newline at end of file
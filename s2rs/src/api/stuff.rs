@@ -1,8 +1,11 @@
 use serde::Deserialize;
 use crate::Api;
+use reqwest::StatusCode;
 use super::utils::RequestBuilderUtils;
+use crate::date::Date;
 
 #[derive(Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StuffAuthor {
     pub admin: bool,
     #[serde( rename = "pk" )]
@@ -15,6 +18,7 @@ pub struct StuffAuthor {
 
 // region: project
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StuffProject {
     pub fields: StuffProjectFields,
     #[serde( rename = "pk" )]
@@ -22,15 +26,17 @@ pub struct StuffProject {
 }
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StuffProjectFields {
     #[serde( rename = "creator" )]
     pub author: StuffAuthor,
     #[serde( rename = "datetime_created" )]
-    pub created_at: String,
+    pub created_at: Date,
     #[serde( rename = "datetime_modified" )]
-    pub modified_at: String,
+    pub modified_at: Date,
+    /// `None` for projects which were never shared
     #[serde( rename = "datetime_shared" )]
-    pub shared_at: Option<String>,
+    pub shared_at: Option<Date>,
     pub favorite_count: u32,
     #[serde( rename = "isPublished" )]
     pub public: bool,
@@ -44,39 +50,11 @@ pub struct StuffProjectFields {
     pub commenters_count: u64,
 }
 
-#[derive(Deserialize, Debug, Clone)]
-pub struct StuffSharedProject {
-    pub fields: StuffSharedProjectFields,
-    #[serde( rename = "pk" )]
-    pub id: u64,
-}
-
-#[derive(Deserialize, Debug, Clone)]
-pub struct StuffSharedProjectFields {
-    #[serde( rename = "creator" )]
-    pub author: StuffAuthor,
-    #[serde( rename = "datetime_created" )]
-    pub created_at: String,
-    #[serde( rename = "datetime_modified" )]
-    pub modified_at: String,
-    #[serde( rename = "datetime_shared" )]
-    pub shared_at: String,
-    pub favorite_count: u32,
-    #[serde( rename = "isPublished" )]
-    pub public: bool,
-    pub love_count: u32,
-    #[serde( rename = "remixers_count" )]
-    pub remix_count: u32,
-    pub thumbnail_url: String,
-    pub title: String,
-    pub view_count: u32,
-    pub visibility: String,
-    pub commenters_count: u64,
-}
 // endregion: project
 
 // region: studio
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StuffStudio {
     pub fields: StuffStudioFields,
     #[serde( rename = "pk" )]
@@ -84,13 +62,15 @@ pub struct StuffStudio {
 }
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StuffStudioFields {
     pub commenters_count: u64,
+    #[serde( rename = "curators_count" )]
     pub curator_count: u32,
     #[serde( rename = "datetime_created" )]
-    pub created_at: String,
+    pub created_at: Date,
     #[serde( rename = "datetime_modified" )]
-    pub modified_at: String,
+    pub modified_at: Date,
     pub owner: StuffAuthor,
     #[serde( rename = "projecters_count" )]
     pub projects_count: u32,
@@ -99,34 +79,125 @@ pub struct StuffStudioFields {
 }
 // endregion: studio
 
+// region: StuffSort
+/// Order of My Stuff listings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StuffSort {
+    #[default]
+    Modified,
+    Created,
+    /// Only for projects
+    Shared,
+    Title,
+    /// Only for projects
+    Views,
+    /// Only for projects
+    Loves,
+    /// Only for projects
+    Favorites,
+    /// Only for projects
+    Remixes,
+    /// Only for studios
+    Projects,
+    /// Only for studios
+    Curators,
+}
+
+impl AsRef<str> for StuffSort {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Modified => "datetime_modified",
+            Self::Created => "datetime_created",
+            Self::Shared => "datetime_shared",
+            Self::Title => "title",
+            Self::Views => "view_count",
+            Self::Loves => "love_count",
+            Self::Favorites => "favorite_count",
+            Self::Remixes => "remixers_count",
+            Self::Projects => "projecters_count",
+            Self::Curators => "curators_count",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    Ascending,
+    #[default]
+    Descending,
+}
+
+impl StuffSort {
+    pub fn as_query(&self, direction: SortDirection) -> [(&str, &str); 2] {
+        match direction {
+            SortDirection::Ascending => [("ascsort", self.as_ref()), ("descsort", "")],
+            SortDirection::Descending => [("ascsort", ""), ("descsort", self.as_ref())],
+        }
+    }
+}
+// endregion: StuffSort
+
+// region: StuffFilter
+/// Which projects of My Stuff are listed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StuffFilter {
+    #[default]
+    All,
+    Shared,
+    NotShared,
+    Trashed,
+}
+
+impl AsRef<str> for StuffFilter {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::All => "all",
+            Self::Shared => "shared",
+            Self::NotShared => "notshared",
+            Self::Trashed => "trashed",
+        }
+    }
+}
+// endregion: StuffFilter
+
+/// Amount of items on one My Stuff page
+pub const STUFF_PAGE_LIMIT: usize = 40;
+
 impl Api {
-    pub async fn stuff_all(&self, page: u32, sort_by: &str) -> super::Result<Vec<StuffProject>> {
-        let response = self.get_site_api("projects/all/").query(&[("page", page)])
-        .query(&[("ascsort", ""), ("descsort", sort_by)]).send_success().await?;
-        Ok(response.json().await?)
+    /// One page of own projects, `page` starts at 1
+    /// - Scratch responds with 404 for pages past the last one, so it's returned as an empty page
+    pub async fn stuff_projects(&self, filter: StuffFilter, page: u32, sort: StuffSort, direction: SortDirection) -> super::Result<Vec<StuffProject>> {
+        let response = self.get_site_api(&format!["projects/{}/", filter.as_ref()]).query(&[("page", page)])
+        .query(&sort.as_query(direction)).send_shared(&self.in_flight).await?;
+        if response.status == StatusCode::NOT_FOUND {
+            return Ok(Vec::new())
+        }
+        Ok(response.only_success()?.json()?)
     }
 
-    pub async fn stuff_shared(&self, page: u32, sort_by: &str) -> super::Result<Vec<StuffSharedProject>> {
-        let response = self.get_site_api("projects/shared/").query(&[("page", page)])
-        .query(&[("ascsort", ""), ("descsort", sort_by)]).send_success().await?;
-        Ok(response.json().await?)
+    pub async fn stuff_all(&self, page: u32, sort: StuffSort, direction: SortDirection) -> super::Result<Vec<StuffProject>> {
+        self.stuff_projects(StuffFilter::All, page, sort, direction).await
     }
 
-    pub async fn stuff_unshared(&self, page: u32, sort_by: &str) -> super::Result<Vec<StuffProject>> {
-        let response = self.get_site_api("projects/notshared/").query(&[("page", page)])
-        .query(&[("ascsort", ""), ("descsort", sort_by)]).send_success().await?;
-        Ok(response.json().await?)
+    pub async fn stuff_shared(&self, page: u32, sort: StuffSort, direction: SortDirection) -> super::Result<Vec<StuffProject>> {
+        self.stuff_projects(StuffFilter::Shared, page, sort, direction).await
     }
 
-    pub async fn stuff_trashed(&self, page: u32, sort_by: &str) -> super::Result<Vec<StuffProject>> {
-        let response = self.get_site_api("projects/trashed/").query(&[("page", page)])
-        .query(&[("ascsort", ""), ("descsort", sort_by)]).send_success().await?;
-        Ok(response.json().await?)
+    pub async fn stuff_unshared(&self, page: u32, sort: StuffSort, direction: SortDirection) -> super::Result<Vec<StuffProject>> {
+        self.stuff_projects(StuffFilter::NotShared, page, sort, direction).await
     }
 
-    pub async fn stuff_studios(&self, page: u32, sort_by: &str) -> super::Result<Vec<StuffAuthor>> {
+    pub async fn stuff_trashed(&self, page: u32, sort: StuffSort, direction: SortDirection) -> super::Result<Vec<StuffProject>> {
+        self.stuff_projects(StuffFilter::Trashed, page, sort, direction).await
+    }
+
+    /// One page of own studios, `page` starts at 1
+    pub async fn stuff_studios(&self, page: u32, sort: StuffSort, direction: SortDirection) -> super::Result<Vec<StuffStudio>> {
         let response = self.get_site_api("galleries/all/").query(&[("page", page)])
-        .query(&[("ascsort", ""), ("descsort", sort_by)]).send_success().await?;
-        Ok(response.json().await?)
+        .query(&sort.as_query(direction)).send_shared(&self.in_flight).await?;
+        if response.status == StatusCode::NOT_FOUND {
+            return Ok(Vec::new())
+        }
+        Ok(response.only_success()?.json()?)
     }
-}
\ No newline at end of file
+}
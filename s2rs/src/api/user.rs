@@ -3,10 +3,13 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use crate::{cursor::Cursor, json};
 use reqwest::StatusCode;
-use super::{Api, utils::RequestBuilderUtils, FeaturedLabel, SendComment};
+use super::{Api, utils::RequestBuilderUtils, FeaturedLabel, SendComment, CacheEndpoint};
+use crate::date::Date;
+use crate::Country;
 
 // region: User
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct User {
     pub id: u64,
     #[serde( rename = "username" )]
@@ -17,16 +20,18 @@ pub struct User {
     pub profile: UserProfile,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserProfile {
     pub id: u64,
     pub images: UserProfileImages,
     pub status: String,
     pub bio: String,
-    pub country: String,
+    pub country: Country,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserProfileImages {
     #[serde( rename = "90x90" )]
     pub x90: String,
@@ -40,14 +45,16 @@ pub struct UserProfileImages {
     pub x32: String,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserHistory {
-    pub joined: String
+    pub joined: Date
 }
 // endregion: User
 
 // region: UserNameCheck
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum UserNameCheck {
     Valid,
     Invalid,
@@ -101,43 +108,53 @@ pub enum SetUserIconError {
 
 #[derive(Forwarder, Debug)]
 pub enum GetUserMessagesCountError {
-    #[forward(reqwest::Error)]
+    #[forward(reqwest::Error, serde_json::Error)]
     This(super::Error),
     #[forward] Parsing(json::ExpectedError),
 }
 
 impl Api {
     pub async fn user_meta(&self, name: &str) -> super::Result<User> {
-        let response = self.get(&format!["users/{name}"]).send_success().await?;
-        Ok(response.json().await?)
+        let data = self.get_cached(CacheEndpoint::UserMeta, &format!["users/{name}"], false).await?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    /// Same as [`Api::user_meta`], but skips cached response
+    pub async fn refresh_user_meta(&self, name: &str) -> super::Result<User> {
+        let data = self.get_cached(CacheEndpoint::UserMeta, &format!["users/{name}"], true).await?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    pub fn invalidate_user_meta(&self, name: &str) {
+        self.invalidate_cached(&format!["users/{name}"])
     }
 
     pub async fn user_messages_count(&self, name: &str) -> Result<u64, GetUserMessagesCountError> {
-        let response = self.get(&format!["users/{name}/messages/count"]).send_success().await?;
+        let response = self.get(&format!["users/{name}/messages/count"]).send_shared_success(&self.in_flight).await?;
 
-        let data: json::Parser = response.json().await?;
+        let data: json::Parser = response.json()?;
         let count = data.i("count").u64()?;
         Ok(count)
     }
 
     pub async fn user_followers(&self, name: &str, cursor: impl Into<Cursor>) -> super::Result<Vec<User>> {
-        let response = self.get(&format!["users/{name}/followers/"]).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+        let response = self.get(&format!["users/{name}/followers/"]).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
     pub async fn user_following(&self, name: &str, cursor: impl Into<Cursor>) -> super::Result<Vec<User>> {
-        let response = self.get(&format!["users/{name}/following"]).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+        let response = self.get(&format!["users/{name}/following"]).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
     pub async fn studio_managers(&self, id: u64, cursor: impl Into<Cursor>) -> super::Result<Vec<User>> {
-        let response = self.get(&format!["studios/{id}/managers"]).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+        let response = self.get(&format!["studios/{id}/managers"]).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
     pub async fn studio_curators(&self, id: u64, cursor: impl Into<Cursor>) -> super::Result<Vec<User>> {
-        let response = self.get(&format!["studios/{id}/curators/"]).cursor(cursor).send_success().await?;
-        Ok(response.json().await?)
+        let response = self.get(&format!["studios/{id}/curators/"]).cursor(cursor).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
     pub async fn follow_user(&self, name: &str) -> super::Result<Value> {
@@ -154,9 +171,16 @@ impl Api {
         Ok(response.json().await?)
     }
 
+    /// Posts comment on profile of user `name`
+    /// - `parent_id` is the top level comment of the thread and `to_id` is the user replied to, site api expects both as strings
     pub async fn send_user_comment(&self, name: &str, data: &SendComment) -> super::Result<()> {
+        let id_or_empty = |id: Option<u64>| id.map(|id| id.to_string()).unwrap_or_default();
         self.post_site_api(&format!["comments/user/{name}/add/"])
-        .json(&serde_json::to_string(data)?).send_success().await?;
+        .json(&serde_json::json!({
+            "content": data.content,
+            "parent_id": id_or_empty(data.parent_id),
+            "commentee_id": id_or_empty(data.to_id),
+        })).send_success().await?;
         Ok(())
     }
 
@@ -173,8 +197,8 @@ impl Api {
     }
 
     pub async fn check_user_name(&self, name: &str) -> super::Result<UserNameCheck> {
-        let response = self.get(&format!["accounts/checkusername/{name}/"]).send_success().await?;
-        Ok(response.json().await?)
+        let response = self.get(&format!["accounts/checkusername/{name}/"]).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 
     pub async fn user_icon(&self, id: u64, width: u16, height: u16) -> super::Result<Vec<u8>> {
@@ -1,93 +1,173 @@
 use super::Api;
-#[cfg(feature = "html")] use html_parser::{Dom, Element};
+#[cfg(feature = "html")] use html_parser::{Dom, Element, Node};
 use s2rs_derive::Forwarder;
 use serde_json::json;
 use super::utils::RequestBuilderUtils;
-// const NUMBERS: &str = "1234567890";
+#[cfg(feature = "html")] use reqwest::StatusCode;
+#[cfg(feature = "html")] use crate::html::{ElementUtils, decode_entities};
+use crate::date::Date;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum CommentContentFragment {
     Link {
         to: String,
         content: String,
     },
     Text(String),
-    Emoji(String)
+    Emoji(String),
+    /// Markup that isn't a link or an emoji, such as `<b>` or `<br>`
+    Raw {
+        tag: String,
+        content: CommentContent,
+    },
 }
 
-#[derive(Debug)]
+impl CommentContentFragment {
+    #[cfg(feature = "html")]
+    fn from_html(node: &Node) -> Option<Self> {
+        match node {
+            Node::Text(text) => Some(Self::Text(decode_entities(text))),
+            Node::Element(element) => {
+                let attribute = |name| element.get_attribute(name).map(|value| decode_entities(&value));
+                Some(match element.name.as_str() {
+                    "a" => match attribute("href") {
+                        Some(to) => Self::Link { to, content: element.text_content() },
+                        None => Self::raw(element)
+                    },
+                    "img" => match attribute("src") {
+                        Some(src) => Self::Emoji(src),
+                        None => Self::raw(element)
+                    },
+                    _ => Self::raw(element)
+                })
+            },
+            Node::Comment(_) => None
+        }
+    }
+
+    #[cfg(feature = "html")]
+    fn raw(element: &Element) -> Self {
+        Self::Raw {
+            tag: element.name.clone(),
+            content: CommentContent::from_html(element)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CommentContent(pub Vec<CommentContentFragment>);
 
 impl CommentContent {
     #[cfg(feature = "html")]
-    fn try_from_html(element: &Element) -> Option<Self> {
-        use crate::html::ElementUtils;
-
-        fn get_link_data(element: &Element) -> Option<(String, String)> {
-            if let Some(text) = element.get_text() {
-                Some((text.to_owned(), element.get_attribute("href")?))
-            } else {
-                get_link_data(element.children.first()?.element()?)
-            }
-        }
+    fn from_html(element: &Element) -> Self {
+        Self(element.children.iter().filter_map(CommentContentFragment::from_html).collect())
+    }
 
-        let mut fragments = Vec::new();
-
-        for node in &element.children {
-            if let Some(text) = node.text() {
-                fragments.push(CommentContentFragment::Text(text.to_owned()));
-
-            } else if let Some(element) = node.element() {
-                match element.name.as_str() {
-                    "a" => {
-                        let (content, to) = get_link_data(element)?;
-                            fragments.push(CommentContentFragment::Link { content, to })
-                    }
-                    "img" => fragments.push(CommentContentFragment::Emoji(element.get_attribute("src")?)),
-                    _ => None?
-                }
+    /// Content as plain text, emojis are left out
+    pub fn text(&self) -> String {
+        let mut result = String::new();
+        for fragment in &self.0 {
+            match fragment {
+                CommentContentFragment::Text(text) => result.push_str(text),
+                CommentContentFragment::Link { content, .. } => result.push_str(content),
+                CommentContentFragment::Emoji(_) => {},
+                CommentContentFragment::Raw { content, .. } => result.push_str(&content.text()),
             }
         }
-        Some(Self(fragments))
+        result
     }
 }
 
+// region: ParseUserCommentError
+/// Single comment or reply of a page couldn't be parsed
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ParseUserCommentError {
+    /// `None` when the id itself couldn't be read
+    pub id: Option<u64>,
+    /// Part of the comment markup which is missing or malformed
+    pub part: &'static str,
+}
+// endregion: ParseUserCommentError
+
+// region: CommentInfo
+/// Markup shared by comments and replies
+#[cfg(feature = "html")]
+struct CommentInfo {
+    id: u64,
+    author_name: String,
+    author_id: u64,
+    avatar_url: String,
+    content: CommentContent,
+    created_at: Date,
+}
+
+#[cfg(feature = "html")]
+impl CommentInfo {
+    fn from_html(element: &Element) -> Result<Self, ParseUserCommentError> {
+        let info = element.child_by_class("comment");
+        let id = info.and_then(|info| info.get_attribute("data-comment-id")?.parse().ok());
+        let error = |part| ParseUserCommentError { id, part };
+
+        let info = info.ok_or(error("comment"))?;
+        let inner_info = info.child_by_class("info").ok_or(error("info"))?;
+        let extra_info = inner_info.children.iter().filter_map(Node::element)
+        .find(|element| element.child_by_class("time").is_some())
+        .ok_or(error("time"))?;
+
+        Ok(Self {
+            id: id.ok_or(error("id"))?,
+            author_name: inner_info.child_by_class("name").and_then(|name| name.child_by_name("a"))
+            .map(ElementUtils::text_content).map(|name| name.trim().to_owned())
+            .ok_or(error("author_name"))?,
+            author_id: extra_info.child_by_name("a").and_then(|link| link.get_attribute("data-commentee-id")?.parse().ok())
+            .ok_or(error("author_id"))?,
+            avatar_url: info.child_by_id("comment-user").and_then(|user| user.child_by_class("avatar")?.get_attribute("src"))
+            .unwrap_or_default(),
+            content: CommentContent::from_html(inner_info.child_by_class("content").ok_or(error("content"))?),
+            created_at: crate::date::parse(extra_info.child_by_class("time").and_then(|time| time.get_attribute("title")).ok_or(error("created_at"))?),
+        })
+    }
+}
+// endregion: CommentInfo
+
 // region: UserReply
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserReply {
     pub id: u64,
+    pub parent_id: u64,
     pub profile_name: String,
     pub author_name: String,
     pub author_id: u64,
     pub avatar_url: String,
     pub content: CommentContent,
-    pub created_at: String,
+    pub created_at: Date,
 }
 
 impl UserReply {
     #[cfg(feature = "html")]
-    fn try_from_html(element: &Element, profile_name: String) -> Option<Self> {
-        use crate::html::ElementUtils;
-
-        let info = element.child_by_class("comment")?;
-        let inner_info = info.child_by_class("info")?;
-        let extra_info = inner_info.children.get(2)?.element()?;
-
-        Some(Self {
-            avatar_url: info.child_by_id("comment-user")?.child_by_class("avatar")?.get_attribute("src")?,
-            author_name: inner_info.child_by_class("name")?.child_by_name("a")?.get_text()?.to_owned(),
-            content: CommentContent::try_from_html(inner_info.child_by_class("content")?)?,
-            created_at: extra_info.child_by_class("time")?.get_attribute("title")?,
-            author_id: extra_info.child_by_name("a")?.get_attribute("data-commentee-id")?.parse().ok()?,
-            id: info.get_attribute("data-comment-id")?.parse().ok()?,
-            profile_name
+    fn from_html(element: &Element, parent_id: u64, profile_name: String) -> Result<Self, ParseUserCommentError> {
+        let info = CommentInfo::from_html(element)?;
+        Ok(Self {
+            id: info.id,
+            parent_id,
+            profile_name,
+            author_name: info.author_name,
+            author_id: info.author_id,
+            avatar_url: info.avatar_url,
+            content: info.content,
+            created_at: info.created_at,
         })
     }
 }
 // endregion: UserReply
 
 // region: UserComment
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserComment {
     pub id: u64,
     pub profile_name: String,
@@ -95,88 +175,96 @@ pub struct UserComment {
     pub author_id: u64,
     pub avatar_url: String,
     pub content: CommentContent,
-    pub created_at: String,
-    pub replies: Vec<UserReply>,
-    // pub reply_count: u32,
+    pub created_at: Date,
+    /// Replies shown along with the comment, use [`Api::user_comment_replies`] to get the rest
+    pub replies: Vec<Result<UserReply, ParseUserCommentError>>,
+    /// Count of all replies, including those not in `replies`
+    pub reply_count: u32,
 }
 
 impl UserComment {
     #[cfg(feature = "html")]
-    fn try_from_html(element: &Element, profile_name: String) -> Option<Self> {
-        use crate::html::ElementUtils;
-
-        let info = element.child_by_class("comment")?;
-        let inner_info = info.child_by_class("info")?;
-        let extra_info = inner_info.children.get(2)?.element()?;
-        let mut replies = Vec::new();
-        for node in &element.child_by_class("replies")?.children {
-            if let Some(element) = node.element() {
-                if element.classes.contains(&"reply".to_string()) {
-                    replies.push(UserReply::try_from_html(element, profile_name.clone())?);
-                }
-            }
-        }
+    fn from_html(element: &Element, profile_name: String) -> Result<Self, ParseUserCommentError> {
+        let info = CommentInfo::from_html(element)?;
+        let replies = match element.child_by_class("replies") {
+            Some(replies) => parse_replies(&replies.children, info.id, &profile_name),
+            None => Vec::new()
+        };
 
-        Some(Self {
-            avatar_url: info.child_by_id("comment-user")?.child_by_class("avatar")?.get_attribute("src")?,
-            author_name: inner_info.child_by_class("name")?.child_by_name("a")?.get_text()?.to_owned(),
-            content: CommentContent::try_from_html(inner_info.child_by_class("content")?)?,
-            created_at: extra_info.child_by_class("time")?.get_attribute("title")?,
-            author_id: extra_info.child_by_name("a")?.get_attribute("data-commentee-id")?.parse().ok()?,
-            id: info.get_attribute("data-comment-id")?.parse().ok()?,
+        Ok(Self {
+            id: info.id,
             profile_name,
-            replies
-            // reply_count: match element.child_by_class("more-replies") {
-            //     Some(element) => {
-            //         dbg!(element.child_by_class("pulldown"));
-            //         let text = element.child_by_class("pulldown")?.get_text()?;
-            //         let mut num = "".to_string();
-            //         let mut iter = text.chars().skip(text.find_tail("See all ")?);
-            //         loop {
-            //             let character = iter.next()?;
-            //             if NUMBERS.contains(character) {
-            //                 num.push(character.to_owned());
-            //             } else {
-            //                 break;
-            //             }
-
-            //         }
-            //         num.parse().ok()
-            //     },
-
-            //     None => replies.len().try_into().ok()
-            // }?,
+            author_name: info.author_name,
+            author_id: info.author_id,
+            avatar_url: info.avatar_url,
+            content: info.content,
+            created_at: info.created_at,
+            reply_count: more_replies_count(element).unwrap_or(replies.len() as u32),
+            replies,
         })
     }
 }
+
+/// Parses every `.reply` element in `nodes`
+#[cfg(feature = "html")]
+fn parse_replies<'a>(nodes: impl IntoIterator<Item = &'a Node>, parent_id: u64, profile_name: &str) -> Vec<Result<UserReply, ParseUserCommentError>> {
+    nodes.into_iter().filter_map(Node::element)
+    .filter(|element| element.classes.iter().any(|class| class == "reply"))
+    .map(|element| UserReply::from_html(element, parent_id, profile_name.to_owned()))
+    .collect()
+}
+
+/// Reads `N` from the "See all N replies" link of collapsed threads
+#[cfg(feature = "html")]
+fn more_replies_count(element: &Element) -> Option<u32> {
+    let text = element.child_by_class("more-replies")?.child_by_class("pulldown")?.text_content();
+    text.split_whitespace().find_map(|word| word.parse().ok())
+}
 // endregion: UserComment
 
 #[derive(Debug, Forwarder)]
 pub enum GetUserCommentsError {
     Parsing,
-    #[forward(reqwest::Error)]
+    #[forward(reqwest::StatusCode, reqwest::Error)]
     This(super::Error)
 }
 
+/// Amount of comments on one page of a profile or of a reply thread
+pub const USER_COMMENTS_PAGE_LIMIT: usize = 40;
+
 impl Api {
+    /// One page of profile comments, `page` starts at 1
+    /// - Scratch responds with 404 for pages past the last one, so it's returned as an empty page
     #[cfg(feature = "html")]
-    pub async fn user_comments(&self, name: &str, page: Option<u8>) -> Result<Vec<UserComment>, GetUserCommentsError> {
-        let response = self.get_site_api(&format!["comments/user/{name}/"]).query(&[("page", page)]).send_success().await?;
-        let data = response.text().await?;
+    /// - Comments which can't be parsed are returned as errors in their place, so the rest of the page is still usable
+    pub async fn user_comments(&self, name: &str, page: Option<u32>) -> Result<Vec<Result<UserComment, ParseUserCommentError>>, GetUserCommentsError> {
+        let response = self.get_site_api(&format!["comments/user/{name}/"]).query(&[("page", page)]).send_shared(&self.in_flight).await?;
+        if response.status == StatusCode::NOT_FOUND {
+            return Ok(Vec::new())
+        }
+        let data = response.only_success()?.body.clone();
 
         let dom = Dom::parse(&data).ok().ok_or(GetUserCommentsError::Parsing)?;
 
-        let mut result = Vec::new();
+        Ok(dom.children.iter().filter_map(Node::element)
+        .filter(|element| element.classes.iter().any(|class| class == "top-level-reply"))
+        .map(|element| UserComment::from_html(element, name.to_owned()))
+        .collect())
+    }
 
-        for node in &dom.children {
-            if let Some(element) = node.element() {
-                if element.classes.contains(&"top-level-reply".to_string()) {
-                    result.push(UserComment::try_from_html(element, name.to_owned()).ok_or(GetUserCommentsError::Parsing)?);
-                }
-            }
+    /// Whole reply thread of a profile comment, including replies hidden behind "See all N replies"
+    #[cfg(feature = "html")]
+    pub async fn user_comment_replies(&self, name: &str, id: u64, page: Option<u32>) -> Result<Vec<Result<UserReply, ParseUserCommentError>>, GetUserCommentsError> {
+        let response = self.get_site_api(&format!["comments/user/{name}/replies/"])
+        .query(&[("comment_id", Some(id)), ("page", page.map(u64::from))])
+        .send_shared(&self.in_flight).await?;
+        if response.status == StatusCode::NOT_FOUND {
+            return Ok(Vec::new())
         }
-        
-        Ok(result)
+        let data = response.only_success()?.body.clone();
+
+        let dom = Dom::parse(&data).ok().ok_or(GetUserCommentsError::Parsing)?;
+        Ok(parse_replies(&dom.children, id, name))
     }
 }
 
@@ -187,4 +275,12 @@ impl Api {
         })).send_success().await?;
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// Deletes comment from profile of user `name`, only works on own profile
+    pub async fn delete_user_comment(&self, name: &str, id: u64) -> super::Result<()> {
+        self.post_site_api(&format!["comments/user/{name}/del/"]).json(&json!({
+            "id": id.to_string()
+        })).send_success().await?;
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use serde::Deserialize;
 use crate::Api;
 use super::utils::RequestBuilderUtils;
+use crate::date::Date;
 
 // region: FeaturedLabel
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -47,6 +48,7 @@ impl FeaturedLabel {
 // endregion: FeaturedLabel
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserFeatured {
     pub id: u64, // not sure what this field is for
     #[serde( rename = "featured_project_label_name", deserialize_with = "FeaturedLabel::deserialize" )]
@@ -62,6 +64,7 @@ pub struct UserFeatured {
 }
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserFeaturedProject {
     #[serde( rename = "creator" )]
     pub author_name: String,
@@ -69,10 +72,11 @@ pub struct UserFeaturedProject {
     pub id: u64,
     pub title: String,
     #[serde( rename = "datetime_modified" )]
-    pub modified_at: String,
+    pub modified_at: Date,
 }
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserFeaturedProfile {
     #[serde( rename = "username" )]
     pub name: String,
@@ -82,7 +86,7 @@ pub struct UserFeaturedProfile {
 
 impl Api {
     pub async fn user_featured(&self, name: &str) -> super::Result<UserFeatured> {
-        let response = self.get_site_api(&format!["users/all/{name}/"]).send_success().await?;
-        Ok(response.json().await?)
+        let response = self.get_site_api(&format!["users/all/{name}/"]).send_shared_success(&self.in_flight).await?;
+        Ok(response.json()?)
     }
 }
\ No newline at end of file
@@ -1,14 +1,14 @@
+use std::sync::Arc;
 use async_trait::async_trait;
 use reqwest::{Response, RequestBuilder, StatusCode};
+use super::single_flight::{SingleFlight, SharedResponse};
 // use serde::{de::DeserializeOwned, Serialize};
-use crate::{cursor::Cursor, json};
+use crate::cursor::Cursor;
 
 #[async_trait]
 pub trait ResponseUtils where Self: Sized {
     fn only_success(self) -> Result<Self, StatusCode>;
     // async fn json<'a, T: DeserializeOwned>(self) -> Result<T, super::Error>;
-    async fn json_parser<T: json::Parsable, E: From<T::Error> + From<reqwest::Error>>(self) -> Result<T, E>;
-    async fn json_parser_vec<T: json::Parsable, E: From<T::Error> + From<reqwest::Error>>(self) -> Result<Vec<T>, E>;
 }
 
 #[async_trait]
@@ -25,20 +25,15 @@ impl ResponseUtils for Response {
     //     let text = self.text().await?;
     //     Ok(serde_json::from_str::<T>(&text)?)
     // }
-
-    async fn json_parser<T: json::Parsable, E: From<T::Error> + From<reqwest::Error>>(self) -> Result<T, E> {
-        Ok(T::parse(&self.json::<json::Parser>().await?)?)
-    }
-
-    async fn json_parser_vec<T: json::Parsable, E: From<T::Error> + From<reqwest::Error>>(self) -> Result<Vec<T>, E> {
-        Ok(T::parse_vec(&self.json::<Vec<json::Parser>>().await?)?)
-    }
 }
 
 #[async_trait]
 pub trait RequestBuilderUtils where Self: Sized {
     async fn send_success(self) -> Result<Response, super::Error>;
     async fn project_send_success(self, id: u64) -> Result<Response, super::Error>;
+    /// Sends GET request, sharing the response with concurrent identical requests
+    async fn send_shared(self, in_flight: &SingleFlight) -> Result<Arc<SharedResponse>, super::Error>;
+    async fn send_shared_success(self, in_flight: &SingleFlight) -> Result<Arc<SharedResponse>, super::Error>;
     fn cursor(self, cursor: impl Into<Cursor>) -> Self;
     fn cursor_limited(self, cursor: impl Into<Cursor>, limit: usize) -> Self;
     // fn json<T: Serialize>(self, data: T) -> Result<Self, serde_json::Error>;
@@ -56,6 +51,19 @@ impl RequestBuilderUtils for RequestBuilder {
         self.project_referer(id).send_success().await
     }
 
+    async fn send_shared(self, in_flight: &SingleFlight) -> Result<Arc<SharedResponse>, super::Error> {
+        let key = self.try_clone().and_then(|request| request.build().ok())
+        .map(|request| format!["{} {}", request.method(), request.url()]);
+        match key {
+            Some(key) => Ok(in_flight.send(key, self).await?),
+            None => Ok(Arc::new(SharedResponse::fetch(self).await?))
+        }
+    }
+
+    async fn send_shared_success(self, in_flight: &SingleFlight) -> Result<Arc<SharedResponse>, super::Error> {
+        Ok(self.send_shared(in_flight).await?.only_success()?)
+    }
+
     fn project_referer(self, id: u64) -> Self {
         self.header("referer", format!("https://scratch.mit.edu/projects/{id}"))
     }
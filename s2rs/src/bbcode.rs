@@ -0,0 +1,568 @@
+//! BBCode used by Scratch forums
+//! - [`BBCode::parse`] never fails, markup which isn't valid BBCode is kept as text
+//! - [`BBCode`] displays as BBCode again, so posts can be parsed, changed and sent back
+//! - BBCode has no escaping, so [`Node::Text`] containing tags is written as they are
+//! # Examples
+//! ```
+//! use s2rs::bbcode::{BBCode, Node};
+//! let post = BBCode::parse("[b]Hello[/b] [url=https://scratch.mit.edu]Scratch[/url]");
+//! assert_eq!(post.text(), "Hello Scratch");
+//!
+//! let reply = BBCode(vec![
+//!     Node::quote("griffpatch", post),
+//!     Node::Text("Hi!".to_owned()),
+//! ]);
+//! assert_eq!(reply.to_string(), "[quote=griffpatch][b]Hello[/b] [url=https://scratch.mit.edu]Scratch[/url][/quote]Hi!");
+//! ```
+use std::fmt::{self, Display, Write};
+
+/// Tags nested deeper than this are kept as text
+const MAX_DEPTH: usize = 64;
+
+// region: Node
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Node {
+    Text(String),
+    Bold(Vec<Node>),
+    Italic(Vec<Node>),
+    Underline(Vec<Node>),
+    Strike(Vec<Node>),
+    Center(Vec<Node>),
+    Color {
+        color: String,
+        content: Vec<Node>,
+    },
+    Size {
+        size: String,
+        content: Vec<Node>,
+    },
+    Quote {
+        author: Option<String>,
+        content: Vec<Node>,
+    },
+    /// `[url=to]content[/url]`, or `[url]to[/url]` when `content` is `None`
+    Link {
+        to: String,
+        content: Option<Vec<Node>>,
+    },
+    Image(String),
+    Code {
+        language: Option<String>,
+        content: String,
+    },
+    Scratchblocks(String),
+    List {
+        /// `1` or `a` for ordered lists, `None` for bullet lists
+        style: Option<String>,
+        items: Vec<Vec<Node>>,
+    },
+    /// Any other tag, such as `[wiki]` or `[google]`
+    Tag {
+        name: String,
+        argument: Option<String>,
+        content: Vec<Node>,
+    },
+}
+
+impl Node {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text(text.into())
+    }
+
+    /// Quote of another post, as Scratch inserts when pressing "Quote"
+    pub fn quote(author: impl Into<String>, content: BBCode) -> Self {
+        Self::Quote {
+            author: Some(author.into()),
+            content: content.0,
+        }
+    }
+
+    fn write_text(&self, result: &mut String) {
+        match self {
+            Self::Text(text) => result.push_str(text),
+            Self::Bold(content) | Self::Italic(content) | Self::Underline(content) | Self::Strike(content) | Self::Center(content)
+            | Self::Color { content, .. } | Self::Size { content, .. } | Self::Tag { content, .. }
+            | Self::Link { content: Some(content), .. } => write_nodes_text(content, result),
+            Self::Link { to, content: None } => result.push_str(to),
+            Self::Quote { author, content } => {
+                if !result.is_empty() && !result.ends_with('\n') {
+                    result.push('\n');
+                }
+                if let Some(author) = author {
+                    let _ = writeln!(result, "{author} wrote:");
+                }
+                let mut quoted = String::new();
+                write_nodes_text(content, &mut quoted);
+                for line in quoted.trim().lines() {
+                    let _ = writeln!(result, "> {line}");
+                }
+            },
+            Self::Image(_) => {},
+            Self::Code { content, .. } | Self::Scratchblocks(content) => result.push_str(content),
+            Self::List { style, items } => {
+                for (index, item) in items.iter().enumerate() {
+                    match style {
+                        Some(_) => { let _ = write!(result, "{}. ", index + 1); },
+                        None => result.push_str("- ")
+                    }
+                    let mut text = String::new();
+                    write_nodes_text(item, &mut text);
+                    result.push_str(text.trim());
+                    result.push('\n');
+                }
+            },
+        }
+    }
+
+    fn write_html(&self, result: &mut String) {
+        match self {
+            Self::Text(text) => result.push_str(&escape_html(text).replace('\n', "<br>")),
+            Self::Bold(content) => write_html_element(result, "b", "", content),
+            Self::Italic(content) => write_html_element(result, "i", "", content),
+            Self::Underline(content) => write_html_element(result, "u", "", content),
+            Self::Strike(content) => write_html_element(result, "s", "", content),
+            Self::Center(content) => write_html_element(result, "div", r#" style="text-align:center""#, content),
+            Self::Color { color, content } => match is_safe_style(color) {
+                true => write_html_element(result, "span", &format![r#" style="color:{color}""#], content),
+                false => write_nodes_html(content, result)
+            },
+            Self::Size { size, content } => match is_safe_style(size) {
+                true => write_html_element(result, "span", &format![r#" style="font-size:{}""#, font_size(size)], content),
+                false => write_nodes_html(content, result)
+            },
+            Self::Quote { author, content } => {
+                result.push_str("<blockquote>");
+                if let Some(author) = author {
+                    let _ = write!(result, "<p><b>{} wrote:</b></p>", escape_html(author));
+                }
+                write_nodes_html(content, result);
+                result.push_str("</blockquote>");
+            },
+            Self::Link { to, content } => match (is_safe_url(to), content) {
+                (true, Some(content)) => write_html_element(result, "a", &format![r#" href="{}""#, escape_html(to)], content),
+                (true, None) => { let _ = write!(result, r#"<a href="{0}">{0}</a>"#, escape_html(to)); },
+                (false, Some(content)) => write_nodes_html(content, result),
+                (false, None) => result.push_str(&escape_html(to)),
+            },
+            Self::Image(src) => if is_safe_url(src) {
+                let _ = write!(result, r#"<img src="{}">"#, escape_html(src));
+            },
+            Self::Code { language, content } => {
+                let class = language.as_ref().map(|language| format![r#" class="language-{}""#, escape_html(language)]).unwrap_or_default();
+                let _ = write!(result, "<pre><code{class}>{}</code></pre>", escape_html(content));
+            },
+            Self::Scratchblocks(content) => {
+                let _ = write!(result, r#"<pre class="blocks">{}</pre>"#, escape_html(content));
+            },
+            Self::List { style, items } => {
+                let tag = match style {
+                    Some(style) => match style.as_str() {
+                        "a" => r#"ol type="a""#,
+                        _ => "ol"
+                    },
+                    None => "ul"
+                };
+                let _ = write!(result, "<{tag}>");
+                for item in items {
+                    write_html_element(result, "li", "", item);
+                }
+                result.push_str(if style.is_some() { "</ol>" } else { "</ul>" });
+            },
+            Self::Tag { content, .. } => write_nodes_html(content, result),
+        }
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Bold(content) => write_tag(f, "b", None, content),
+            Self::Italic(content) => write_tag(f, "i", None, content),
+            Self::Underline(content) => write_tag(f, "u", None, content),
+            Self::Strike(content) => write_tag(f, "s", None, content),
+            Self::Center(content) => write_tag(f, "center", None, content),
+            Self::Color { color, content } => write_tag(f, "color", Some(color), content),
+            Self::Size { size, content } => write_tag(f, "size", Some(size), content),
+            Self::Quote { author, content } => write_tag(f, "quote", author.as_deref(), content),
+            Self::Link { to, content: Some(content) } => write_tag(f, "url", Some(to), content),
+            Self::Link { to, content: None } => write!(f, "[url]{to}[/url]"),
+            Self::Image(src) => write!(f, "[img]{src}[/img]"),
+            Self::Code { language: Some(language), content } => write!(f, "[code={language}]{content}[/code]"),
+            Self::Code { language: None, content } => write!(f, "[code]{content}[/code]"),
+            Self::Scratchblocks(content) => write!(f, "[scratchblocks]{content}[/scratchblocks]"),
+            Self::List { style, items } => {
+                match style {
+                    Some(style) => write!(f, "[list={style}]")?,
+                    None => write!(f, "[list]")?
+                }
+                for item in items {
+                    write!(f, "[*]")?;
+                    write_nodes(f, item)?;
+                }
+                write!(f, "[/list]")
+            },
+            Self::Tag { name, argument, content } => write_tag(f, name, argument.as_deref(), content),
+        }
+    }
+}
+// endregion: Node
+
+// region: BBCode
+/// Parsed BBCode of a forum post
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BBCode(pub Vec<Node>);
+
+impl BBCode {
+    /// - Tag names are case-insensitive, tags which aren't closed or are closed out of order are kept as text
+    /// - Content of `[code]`, `[scratchblocks]`, `[img]` and `[url]` without argument isn't parsed
+    /// # Examples
+    /// ```
+    /// use s2rs::bbcode::{BBCode, Node};
+    /// // unclosed tags are kept as text
+    /// assert_eq!(BBCode::parse("[b]bold").0, vec![Node::text("[b]bold")]);
+    /// // tags closed out of order are kept as text too
+    /// assert_eq!(BBCode::parse("[b][i]x[/b]").0, vec![Node::Bold(vec![Node::text("[i]x")])]);
+    /// assert_eq!(BBCode::parse("[B]x[/b]").0, vec![Node::Bold(vec![Node::text("x")])]);
+    /// assert_eq!(BBCode::parse("[code][b]x[/b][/CODE]").0, vec![Node::Code { language: None, content: "[b]x[/b]".to_owned() }]);
+    /// assert_eq!(BBCode::parse("[list]\n[*]one\n[*][b]two[/b][/list]").0, vec![Node::List {
+    ///     style: None,
+    ///     items: vec![vec![Node::text("one\n")], vec![Node::Bold(vec![Node::text("two")])]]
+    /// }]);
+    /// // too deeply nested tags are kept as text
+    /// let deep = format!["{}x{}", "[i]".repeat(100), "[/i]".repeat(100)];
+    /// assert_eq!(BBCode::parse(&deep).to_string(), deep);
+    /// ```
+    pub fn parse(source: &str) -> Self {
+        // with no open tags parser only stops at the end
+        let (nodes, _) = Parser { source, position: 0, open: Vec::new() }.nodes();
+        Self(nodes)
+    }
+
+    /// Post as plain text, images are left out and quotes are prefixed with `> `
+    pub fn text(&self) -> String {
+        let mut result = String::new();
+        write_nodes_text(&self.0, &mut result);
+        result
+    }
+
+    /// Post as HTML, similar to how Scratch renders it
+    /// - Text is escaped and links or images with scripting urls are left out, so the result is safe to embed
+    /// # Examples
+    /// ```
+    /// use s2rs::bbcode::BBCode;
+    /// assert_eq!(BBCode::parse("[b]<script>[/b]").to_html(), "<b>&lt;script&gt;</b>");
+    /// assert_eq!(BBCode::parse("[url=javascript:alert(1)]click[/url]").to_html(), "click");
+    /// assert_eq!(BBCode::parse("[img]JavaScript:alert(1)[/img]").to_html(), "");
+    /// assert_eq!(BBCode::parse("[url]https://scratch.mit.edu[/url]").to_html(), r#"<a href="https://scratch.mit.edu">https://scratch.mit.edu</a>"#);
+    /// ```
+    pub fn to_html(&self) -> String {
+        let mut result = String::new();
+        write_nodes_html(&self.0, &mut result);
+        result
+    }
+}
+
+impl From<&str> for BBCode {
+    fn from(value: &str) -> Self {
+        Self::parse(value)
+    }
+}
+
+impl From<Vec<Node>> for BBCode {
+    fn from(value: Vec<Node>) -> Self {
+        Self(value)
+    }
+}
+
+/// Writes BBCode back, parsing it again gives the same nodes
+/// # Examples
+/// ```
+/// use s2rs::bbcode::BBCode;
+/// let source = "[quote=griffpatch][color=red]Hi[/color][/quote][list=1][*]a[*][url=https://scratch.mit.edu]b[/url][/list][code=js][i][/code]";
+/// let post = BBCode::parse(source);
+/// assert_eq!(post.to_string(), source);
+/// assert_eq!(BBCode::parse(&post.to_string()), post);
+/// ```
+impl Display for BBCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_nodes(f, &self.0)
+    }
+}
+// endregion: BBCode
+
+// region: Parser
+/// Why [`Parser::nodes`] stopped
+enum Stop {
+    End,
+    /// At closing tag of one of the open tags, it's not consumed
+    Close,
+    /// At `[*]` of the innermost list, it's not consumed
+    Item,
+}
+
+struct RawTag<'a> {
+    /// Lowercase name, empty for `[*]`
+    name: String,
+    argument: Option<&'a str>,
+    closing: bool,
+    length: usize,
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    position: usize,
+    /// Names of the tags currently being parsed
+    open: Vec<String>,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.source[self.position..]
+    }
+
+    /// Consumes the tag at the current position and returns its source
+    fn take_tag(&mut self) -> &'a str {
+        let length = read_tag(self.rest()).map(|tag| tag.length).unwrap_or(1);
+        let tag = &self.rest()[..length];
+        self.position += length;
+        tag
+    }
+
+    fn nodes(&mut self) -> (Vec<Node>, Stop) {
+        let mut result = Vec::new();
+        loop {
+            let Some(start) = self.rest().find('[') else {
+                push_text(&mut result, self.rest());
+                self.position = self.source.len();
+                return (result, Stop::End)
+            };
+            push_text(&mut result, &self.rest()[..start]);
+            self.position += start;
+
+            let Some(tag) = read_tag(self.rest()) else {
+                push_text(&mut result, "[");
+                self.position += 1;
+                continue
+            };
+
+            if tag.closing {
+                if self.open.contains(&tag.name) {
+                    return (result, Stop::Close)
+                }
+                push_text(&mut result, self.take_tag());
+            } else if tag.name.is_empty() {
+                if self.open.last().map(String::as_str) == Some("list") {
+                    return (result, Stop::Item)
+                }
+                push_text(&mut result, self.take_tag());
+            } else {
+                let source = self.take_tag();
+                match self.tag(&tag) {
+                    Ok(node) => result.push(node),
+                    Err(nodes) => {
+                        push_text(&mut result, source);
+                        for node in nodes {
+                            match node {
+                                Node::Text(text) => push_text(&mut result, &text),
+                                node => result.push(node)
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Parses the content of `tag` which was just consumed
+    /// - Returns parsed content as error when the tag isn't closed, so it can be kept as text
+    fn tag(&mut self, tag: &RawTag<'a>) -> Result<Node, Vec<Node>> {
+        match tag.name.as_str() {
+            "code" | "scratchblocks" | "img" => return self.raw_tag(tag).ok_or_else(Vec::new),
+            "url" if tag.argument.is_none() => return self.raw_tag(tag).ok_or_else(Vec::new),
+            _ => {}
+        }
+        if self.open.len() >= MAX_DEPTH {
+            return Err(Vec::new())
+        }
+
+        self.open.push(tag.name.clone());
+        let mut items = match tag.name.as_str() {
+            "list" => self.list_items(),
+            _ => vec![self.nodes().0],
+        };
+        self.open.pop();
+
+        if read_tag(self.rest()).filter(|close| close.closing && close.name == tag.name).is_none() {
+            return Err(items.into_iter().flatten().collect())
+        }
+        self.take_tag();
+
+        let argument = tag.argument.map(str::to_owned);
+        if tag.name == "list" {
+            // text before the first `[*]` is usually just a line break
+            if items.first().is_some_and(|item| BBCode(item.clone()).text().trim().is_empty()) {
+                items.remove(0);
+            }
+            return Ok(Node::List { style: argument, items })
+        }
+
+        let content = items.into_iter().flatten().collect();
+        Ok(match (tag.name.as_str(), argument) {
+            ("b", None) => Node::Bold(content),
+            ("i", None) => Node::Italic(content),
+            ("u", None) => Node::Underline(content),
+            ("s", None) => Node::Strike(content),
+            ("center", None) => Node::Center(content),
+            ("color", Some(color)) => Node::Color { color, content },
+            ("size", Some(size)) => Node::Size { size, content },
+            ("quote", author) => Node::Quote { author, content },
+            ("url", Some(to)) => Node::Link { to, content: Some(content) },
+            (_, argument) => Node::Tag { name: tag.name.clone(), argument, content },
+        })
+    }
+
+    /// Content of tags which aren't parsed, up to their closing tag
+    fn raw_tag(&mut self, tag: &RawTag<'a>) -> Option<Node> {
+        let close = format!["[/{}]", tag.name];
+        let end = self.rest().to_ascii_lowercase().find(&close)?;
+        let content = self.rest()[..end].to_owned();
+        self.position += end + close.len();
+
+        let argument = tag.argument.map(str::to_owned);
+        Some(match tag.name.as_str() {
+            "code" => Node::Code { language: argument, content },
+            "scratchblocks" => Node::Scratchblocks(content),
+            "img" => Node::Image(content),
+            _ => Node::Link { to: content, content: None },
+        })
+    }
+
+    /// Content of a list split at each `[*]`, the first entry is what comes before the first `[*]`
+    fn list_items(&mut self) -> Vec<Vec<Node>> {
+        let mut items = Vec::new();
+        loop {
+            let (nodes, stop) = self.nodes();
+            items.push(nodes);
+            match stop {
+                Stop::Item => { self.take_tag(); },
+                Stop::End | Stop::Close => return items
+            }
+        }
+    }
+}
+
+/// Reads `[name]`, `[name=argument]`, `[/name]` or `[*]` at the start of `source`
+fn read_tag(source: &str) -> Option<RawTag<'_>> {
+    let inner = &source[1..source.find(']')?];
+    let length = inner.len() + 2;
+    if inner == "*" {
+        return Some(RawTag { name: String::new(), argument: None, closing: false, length })
+    }
+    if inner.contains(['[', '\n']) {
+        return None
+    }
+
+    let (closing, inner) = match inner.strip_prefix('/') {
+        Some(inner) => (true, inner),
+        None => (false, inner)
+    };
+    let (name, argument) = match inner.split_once('=') {
+        Some((name, argument)) if !closing => (name, Some(argument.trim_matches('"'))),
+        Some(_) => return None,
+        None => (inner, None)
+    };
+    if name.is_empty() || !name.chars().all(|character| character.is_ascii_alphanumeric()) {
+        return None
+    }
+    Some(RawTag { name: name.to_ascii_lowercase(), argument, closing, length })
+}
+
+/// Appends `text`, merging it with the previous text node
+fn push_text(nodes: &mut Vec<Node>, text: &str) {
+    if text.is_empty() {
+        return
+    }
+    match nodes.last_mut() {
+        Some(Node::Text(last)) => last.push_str(text),
+        _ => nodes.push(Node::Text(text.to_owned()))
+    }
+}
+// endregion: Parser
+
+// region: render
+fn write_nodes(f: &mut fmt::Formatter<'_>, nodes: &[Node]) -> fmt::Result {
+    for node in nodes {
+        write!(f, "{node}")?;
+    }
+    Ok(())
+}
+
+fn write_tag(f: &mut fmt::Formatter<'_>, name: &str, argument: Option<&str>, content: &[Node]) -> fmt::Result {
+    match argument {
+        Some(argument) => write!(f, "[{name}={argument}]")?,
+        None => write!(f, "[{name}]")?
+    }
+    write_nodes(f, content)?;
+    write!(f, "[/{name}]")
+}
+
+fn write_nodes_text(nodes: &[Node], result: &mut String) {
+    for node in nodes {
+        node.write_text(result);
+    }
+}
+
+fn write_nodes_html(nodes: &[Node], result: &mut String) {
+    for node in nodes {
+        node.write_html(result);
+    }
+}
+
+fn write_html_element(result: &mut String, tag: &str, attributes: &str, content: &[Node]) {
+    let _ = write!(result, "<{tag}{attributes}>");
+    write_nodes_html(content, result);
+    let _ = write!(result, "</{tag}>");
+}
+
+fn escape_html(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            character => result.push(character)
+        }
+    }
+    result
+}
+
+/// Scratch takes sizes either as CSS keywords or as percentages without `%`
+fn font_size(size: &str) -> String {
+    match size.parse::<u16>() {
+        Ok(percent) => format!["{percent}%"],
+        Err(_) => size.to_owned()
+    }
+}
+
+fn is_safe_style(value: &str) -> bool {
+    !value.is_empty() && value.chars().all(|character| character.is_ascii_alphanumeric() || matches!(character, '#' | '%' | '.' | '-'))
+}
+
+/// Only web links and relative links are rendered, so `javascript:` and similar can't get through
+fn is_safe_url(url: &str) -> bool {
+    let url = url.trim().to_ascii_lowercase();
+    match url.find(':') {
+        Some(colon) => {
+            let scheme = &url[..colon];
+            scheme == "http" || scheme == "https" || scheme.contains(['/', '?', '#'])
+        },
+        None => true
+    }
+}
+// endregion: render
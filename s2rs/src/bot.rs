@@ -0,0 +1,448 @@
+//! Command bots reacting to comments
+//! - `Requires crate feature: 'bot'`
+use std::{sync::{Arc, Mutex}, collections::HashMap, future::Future, time::{Duration, Instant}};
+use derivative::Derivative;
+use futures_util::{StreamExt, future::{self, BoxFuture, Either}, stream::{self, BoxStream}};
+use s2rs_derive::Forwarder;
+use tokio::sync::watch;
+use crate::{api::{self, Api}, session::{Session, Extension, ExtensionPipe}, entities::{Me, MessageEvent, MessageCommentLocation, MessageWatchOptions, WatchMessagesError, AdaptiveInterval, SeenSet}, Cursor};
+
+// region: BotComment
+/// Where a comment was posted, `parent_id` is the thread replies should go to
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommentTarget {
+    Project { id: u64, parent_id: u64 },
+    Studio { id: u64, parent_id: u64 },
+    Profile { name: String, parent_id: u64 },
+}
+
+#[derive(Debug, Clone)]
+pub struct BotComment {
+    pub id: u64,
+    pub author_name: String,
+    pub author_id: Option<u64>,
+    pub content: String,
+    pub target: CommentTarget,
+}
+// endregion: BotComment
+
+// region: BotSource
+#[derive(Debug, Clone)]
+pub enum BotSource {
+    /// Comments mentioned in inbox messages
+    Inbox(MessageWatchOptions),
+    /// Comments on the first page of a studio, along with newest replies in their threads
+    StudioComments {
+        id: u64,
+        interval: AdaptiveInterval,
+    },
+    /// - `Requires crate feature: 'html'`
+    #[cfg(feature = "html")]
+    ProfileComments {
+        name: String,
+        interval: AdaptiveInterval,
+    },
+}
+// endregion: BotSource
+
+// region: BotError
+#[derive(Forwarder, Debug)]
+pub enum BotError {
+    #[forward] Inbox(WatchMessagesError),
+    #[forward] StudioComments(api::Error),
+    #[cfg(feature = "html")] ProfileComments(api::GetUserCommentsError),
+    Handler {
+        command: String,
+        error: api::Error,
+    },
+}
+// endregion: BotError
+
+// region: CommandContext
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct CommandContext {
+    /// Lowercase command name without prefix
+    pub command: String,
+    pub args: Vec<String>,
+    pub comment: BotComment,
+    #[derivative(Debug = "ignore")]
+    pub session: Arc<Session>,
+}
+
+impl CommandContext {
+    /// Replies in the thread of the comment which invoked the command
+    pub async fn reply(&self, content: impl Into<String>) -> api::Result<()> {
+        let to_id = self.comment.author_id;
+        match &self.comment.target {
+            CommentTarget::Project { id, parent_id } => self.session.project(*id).comment(*parent_id).reply(content, to_id).await,
+            CommentTarget::Studio { id, parent_id } => self.session.studio(*id).comment(*parent_id).reply(content, to_id).await,
+            CommentTarget::Profile { name, parent_id } => self.session.user(name.clone()).comment(*parent_id).reply(content, to_id).await,
+        }
+    }
+}
+// endregion: CommandContext
+
+// region: Bot
+type Handler = Arc<dyn Fn(Arc<CommandContext>) -> BoxFuture<'static, api::Result<()>> + Send + Sync>;
+type ErrorHandler = Arc<dyn Fn(BotError) + Send + Sync>;
+
+struct Command {
+    handler: Handler,
+    cooldown: Option<Duration>,
+}
+
+struct BotPipe {
+    session: Arc<Session>,
+    api: Arc<Api>,
+    me: Arc<Me>,
+}
+
+impl Extension for BotPipe {
+    fn extended(pipe: ExtensionPipe, this: Arc<Session>) -> Arc<Self> {
+        Arc::new(Self {
+            session: this,
+            api: pipe.api,
+            me: pipe.me
+        })
+    }
+}
+
+/// Watches comment sources and routes prefixed commands to handlers
+/// - Commands are handled one by one, [`Bot::shutdown`] lets the current handler finish
+/// - Comments by the session user are ignored
+/// # Examples
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// use std::time::Duration;
+/// use s2rs::{session::Session, bot::{Bot, BotSource}, entities::MessageWatchOptions};
+/// # let session = Session::new("YourUsername");
+/// let bot = Bot::new(&session)
+/// .with_prefix("!")
+/// .with_cooldown(Duration::from_secs(10))
+/// .command("ping", |context| async move {
+///     context.reply("pong").await
+/// })
+/// .watch(BotSource::Inbox(MessageWatchOptions::default()));
+/// bot.run().await;
+/// # })
+/// ```
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Bot {
+    pub prefix: String,
+    pub cooldown: Option<Duration>,
+    pub sources: Vec<BotSource>,
+    #[derivative(Debug = "ignore")]
+    commands: HashMap<String, Command>,
+    #[derivative(Debug = "ignore")]
+    on_error: Option<ErrorHandler>,
+    #[derivative(Debug = "ignore")]
+    used: Mutex<HashMap<(String, String), Instant>>,
+    #[derivative(Debug = "ignore")]
+    stop: watch::Sender<bool>,
+    #[derivative(Debug = "ignore")]
+    pipe: Arc<BotPipe>,
+}
+
+impl Bot {
+    pub fn new(session: &Arc<Session>) -> Self {
+        Self {
+            prefix: "!".to_owned(),
+            cooldown: None,
+            sources: Vec::new(),
+            commands: HashMap::new(),
+            on_error: None,
+            used: Mutex::default(),
+            stop: watch::channel(false).0,
+            pipe: session.extend(),
+        }
+    }
+
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Default per user cooldown of every command
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = Some(cooldown);
+        self
+    }
+
+    pub fn watch(mut self, source: BotSource) -> Self {
+        self.sources.push(source);
+        self
+    }
+
+    pub fn on_error(mut self, handler: impl Fn(BotError) + Send + Sync + 'static) -> Self {
+        self.on_error = Some(Arc::new(handler));
+        self
+    }
+
+    pub fn command<F, Fut>(self, name: impl Into<String>, handler: F) -> Self
+    where F: Fn(Arc<CommandContext>) -> Fut + Send + Sync + 'static, Fut: Future<Output = api::Result<()>> + Send + 'static {
+        let cooldown = self.cooldown;
+        self.command_with_cooldown(name, cooldown, handler)
+    }
+
+    /// Same as [`Bot::command`] with its own per user cooldown
+    pub fn command_with_cooldown<F, Fut>(mut self, name: impl Into<String>, cooldown: Option<Duration>, handler: F) -> Self
+    where F: Fn(Arc<CommandContext>) -> Fut + Send + Sync + 'static, Fut: Future<Output = api::Result<()>> + Send + 'static {
+        self.commands.insert(name.into().to_lowercase(), Command {
+            handler: Arc::new(move |context| Box::pin(handler(context))),
+            cooldown
+        });
+        self
+    }
+
+    /// Stops [`Bot::run`] after the command being handled
+    pub fn shutdown(&self) {
+        self.stop.send_replace(true);
+    }
+
+    pub async fn run(&self) {
+        let mut stop = self.stop.subscribe();
+        let mut comments = stream::select_all(self.sources.iter().map(|source| self.source(source)));
+
+        while !*stop.borrow() {
+            let next = comments.next();
+            let stopped = stop.changed();
+            futures_util::pin_mut!(next, stopped);
+            match future::select(next, stopped).await {
+                Either::Left((Some(Ok(comment)), _)) => self.handle(comment).await,
+                Either::Left((Some(Err(error)), _)) => self.error(error),
+                Either::Left((None, _)) => break,
+                Either::Right((Ok(()), _)) => continue,
+                Either::Right((Err(_), _)) => break,
+            }
+        }
+    }
+
+    fn error(&self, error: BotError) {
+        if let Some(on_error) = &self.on_error {
+            on_error(error);
+        }
+    }
+
+    /// Splits `content` into lowercase command name and arguments, leading mentions are skipped
+    pub fn parse_command(&self, content: &str) -> Option<(String, Vec<String>)> {
+        let mut words = content.split_whitespace().skip_while(|word| word.starts_with('@'));
+        let command = words.next()?.strip_prefix(self.prefix.as_str())?.to_lowercase();
+        if command.is_empty() {
+            return None
+        }
+        Some((command, words.map(ToOwned::to_owned).collect()))
+    }
+
+    fn cooled_down(&self, user: &str, command: &str, cooldown: Option<Duration>) -> bool {
+        let Some(cooldown) = cooldown else { return true };
+        let mut used = self.used.lock().unwrap_or_else(|e| e.into_inner());
+        let key = (user.to_lowercase(), command.to_owned());
+        let now = Instant::now();
+        if used.get(&key).is_some_and(|at| now.duration_since(*at) < cooldown) {
+            return false
+        }
+        used.retain(|_, at| now.duration_since(*at) < cooldown);
+        used.insert(key, now);
+        true
+    }
+
+    async fn handle(&self, comment: BotComment) {
+        if comment.author_name.eq_ignore_ascii_case(&self.pipe.me.name) {
+            return
+        }
+        let Some((name, args)) = self.parse_command(&comment.content) else { return };
+        let Some(command) = self.commands.get(&name) else { return };
+        if !self.cooled_down(&comment.author_name, &name, command.cooldown) {
+            return
+        }
+
+        let context = Arc::new(CommandContext {
+            command: name.clone(),
+            args,
+            comment,
+            session: self.pipe.session.clone(),
+        });
+        if let Err(error) = (command.handler)(context).await {
+            self.error(BotError::Handler { command: name, error });
+        }
+    }
+}
+// endregion: Bot
+
+// region: sources
+impl Bot {
+    fn source(&self, source: &BotSource) -> BoxStream<'static, Result<BotComment, BotError>> {
+        match source.clone() {
+            BotSource::Inbox(options) => {
+                let api = self.pipe.api.clone();
+                self.pipe.me.watch_messages(options).filter_map(move |message| {
+                    let api = api.clone();
+                    async move {
+                        match message {
+                            Ok(message) => inbox_comment(&api, &message.actor.name, message.actor.id, &message.event).await.map(Ok),
+                            Err(error) => Some(Err(error.into()))
+                        }
+                    }
+                }).boxed()
+            },
+
+            BotSource::StudioComments { id, interval } => {
+                let api = self.pipe.api.clone();
+                let reply_counts = Arc::new(Mutex::new(HashMap::<u64, u64>::new()));
+                poll_comments(interval, move || {
+                    let (api, reply_counts) = (api.clone(), reply_counts.clone());
+                    async move {
+                        let mut result = Vec::new();
+                        for comment in api.studio_comments(id, Cursor::default()).await? {
+                            // replies are only fetched for threads whose reply count changed since the last poll
+                            let changed = reply_counts.lock().unwrap_or_else(|e| e.into_inner()).get(&comment.id) != Some(&comment.reply_count);
+                            if changed && comment.reply_count > 0 {
+                                // replies are oldest first, only the newest page is fetched
+                                let start = (comment.reply_count as usize).saturating_sub(40);
+                                let replies = api.studio_comment_replies(id, comment.id, Cursor::limited(start, 40)).await?;
+                                result.extend(replies.into_iter().rev().map(|reply| studio_comment(id, comment.id, reply)));
+                            }
+                            reply_counts.lock().unwrap_or_else(|e| e.into_inner()).insert(comment.id, comment.reply_count);
+                            let parent_id = comment.id;
+                            result.push(studio_comment(id, parent_id, comment));
+                        }
+                        Ok(result)
+                    }
+                })
+            },
+
+            #[cfg(feature = "html")]
+            BotSource::ProfileComments { name, interval } => {
+                let user = self.pipe.session.user(name.clone());
+                poll_comments(interval, move || {
+                    let (user, name) = (user.clone(), name.clone());
+                    async move {
+                        let comments = user.comments_page(1).await.map_err(BotError::ProfileComments)?;
+                        let mut result = Vec::new();
+                        for comment in comments.into_iter().flatten() {
+                            let target = CommentTarget::Profile { name: name.clone(), parent_id: comment.id };
+                            for reply in comment.replies.iter().flatten() {
+                                result.push(BotComment {
+                                    id: reply.id,
+                                    author_name: reply.author.name.to_string(),
+                                    author_id: Some(reply.author.id),
+                                    content: reply.content.text(),
+                                    target: target.clone()
+                                });
+                            }
+                            result.push(BotComment {
+                                id: comment.id,
+                                author_name: comment.author.name.to_string(),
+                                author_id: Some(comment.author.id),
+                                content: comment.content.text(),
+                                target
+                            });
+                        }
+                        Ok(result)
+                    }
+                })
+            },
+        }
+    }
+}
+
+fn studio_comment(id: u64, parent_id: u64, comment: api::Comment) -> BotComment {
+    BotComment {
+        id: comment.id,
+        author_name: comment.author.name,
+        author_id: Some(comment.author.id),
+        content: comment.content,
+        target: CommentTarget::Studio { id, parent_id }
+    }
+}
+
+/// Inbox messages don't say which thread a comment belongs to, so it's looked up
+/// - When the lookup fails the comment is treated as the top-level comment of its thread
+async fn inbox_comment(api: &Api, author_name: &str, author_id: u64, event: &MessageEvent) -> Option<BotComment> {
+    let MessageEvent::Comment { location, id, fragment, .. } = event else { return None };
+    let target = match location {
+        MessageCommentLocation::Project(project) => CommentTarget::Project {
+            id: project.id,
+            parent_id: project_comment_parent(api, project.id, *id).await.unwrap_or(*id)
+        },
+        MessageCommentLocation::Studio(studio) => CommentTarget::Studio {
+            id: studio.id,
+            parent_id: api.studio_comment(studio.id, *id).await.ok().and_then(|comment| comment.parent_id).unwrap_or(*id)
+        },
+        MessageCommentLocation::Profile(user) => CommentTarget::Profile {
+            name: user.name.to_string(),
+            parent_id: profile_comment_parent(api, &user.name, *id).await.unwrap_or(*id)
+        },
+    };
+    Some(BotComment {
+        id: *id,
+        author_name: author_name.to_owned(),
+        author_id: Some(author_id),
+        content: fragment.clone(),
+        target
+    })
+}
+
+async fn project_comment_parent(api: &Api, id: u64, comment_id: u64) -> Option<u64> {
+    let author = api.project_meta(id).await.ok()?.author.name?;
+    api.user_project_comment(&author, id, comment_id).await.ok()?.parent_id
+}
+
+/// - Only the first page of profile comments is searched
+#[cfg(feature = "html")]
+async fn profile_comment_parent(api: &Api, name: &str, comment_id: u64) -> Option<u64> {
+    let comments = api.user_comments(name, Some(1)).await.ok()?;
+    comments.into_iter().flatten()
+    .find(|comment| comment.replies.iter().flatten().any(|reply| reply.id == comment_id))
+    .map(|comment| comment.id)
+}
+
+#[cfg(not(feature = "html"))]
+async fn profile_comment_parent(_api: &Api, _name: &str, _comment_id: u64) -> Option<u64> {
+    None
+}
+
+struct CommentPoll<F> {
+    fetch: F,
+    interval: AdaptiveInterval,
+    seen: SeenSet<(CommentTarget, u64)>,
+    pending: Vec<BotComment>,
+    seeded: bool,
+    first: bool,
+}
+
+/// Repeatedly fetches latest comments, emitting only ones posted after the first successful fetch
+fn poll_comments<F, Fut>(interval: AdaptiveInterval, fetch: F) -> BoxStream<'static, Result<BotComment, BotError>>
+where F: Fn() -> Fut + Send + 'static, Fut: Future<Output = Result<Vec<BotComment>, BotError>> + Send {
+    let state = CommentPoll { fetch, interval, seen: SeenSet::default(), pending: Vec::new(), seeded: false, first: true };
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(comment) = state.pending.pop() {
+                return Some((Ok(comment), state))
+            }
+            if !state.first {
+                state.interval.wait().await;
+            }
+            state.first = false;
+            match (state.fetch)().await {
+                Ok(comments) => {
+                    // newest comments come first, `pending` is popped from the back
+                    let seen = &mut state.seen;
+                    state.pending = comments.into_iter().filter(|comment| seen.insert((comment.target.clone(), comment.id))).collect();
+                    if !state.seeded {
+                        state.seeded = true;
+                        state.pending.clear();
+                    }
+                    if state.pending.is_empty() { state.interval.back_off() } else { state.interval.reset() }
+                },
+                Err(error) => {
+                    state.interval.back_off();
+                    return Some((Err(error), state))
+                }
+            }
+        }
+    }).boxed()
+}
+// endregion: sources
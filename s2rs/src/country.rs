@@ -0,0 +1,591 @@
+//! Countries as shown on Scratch profiles
+use serde::{Deserialize, Serialize};
+
+/// Country of a Scratch user
+/// - Names match the ones Scratch shows on profiles, codes are ISO 3166-1 alpha-2
+/// - [`Country::Other`] keeps names this enum doesn't know yet
+/// # Examples
+/// ```
+/// use s2rs::Country;
+/// let country = Country::from_name("Korea, Republic of");
+/// assert_eq!(country, Country::SouthKorea);
+/// assert_eq!(country.code(), Some("KR"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Country {
+    Afghanistan,
+    AlandIslands,
+    Albania,
+    Algeria,
+    AmericanSamoa,
+    Andorra,
+    Angola,
+    Anguilla,
+    Antarctica,
+    AntiguaAndBarbuda,
+    Argentina,
+    Armenia,
+    Aruba,
+    Australia,
+    Austria,
+    Azerbaijan,
+    Bahamas,
+    Bahrain,
+    Bangladesh,
+    Barbados,
+    Belarus,
+    Belgium,
+    Belize,
+    Benin,
+    Bermuda,
+    Bhutan,
+    Bolivia,
+    BosniaAndHerzegovina,
+    Botswana,
+    BouvetIsland,
+    Brazil,
+    BritishIndianOceanTerritory,
+    BritishVirginIslands,
+    Brunei,
+    Bulgaria,
+    BurkinaFaso,
+    Burundi,
+    Cambodia,
+    Cameroon,
+    Canada,
+    CapeVerde,
+    CaribbeanNetherlands,
+    CaymanIslands,
+    CentralAfricanRepublic,
+    Chad,
+    Chile,
+    China,
+    ChristmasIsland,
+    CocosIslands,
+    Colombia,
+    Comoros,
+    Congo,
+    CookIslands,
+    CostaRica,
+    Croatia,
+    Cuba,
+    Curacao,
+    Cyprus,
+    CzechRepublic,
+    DemocraticRepublicOfTheCongo,
+    Denmark,
+    Djibouti,
+    Dominica,
+    DominicanRepublic,
+    Ecuador,
+    Egypt,
+    ElSalvador,
+    EquatorialGuinea,
+    Eritrea,
+    Estonia,
+    Ethiopia,
+    FalklandIslands,
+    FaroeIslands,
+    Fiji,
+    Finland,
+    France,
+    FrenchGuiana,
+    FrenchPolynesia,
+    FrenchSouthernTerritories,
+    Gabon,
+    Gambia,
+    Georgia,
+    Germany,
+    Ghana,
+    Gibraltar,
+    Greece,
+    Greenland,
+    Grenada,
+    Guadeloupe,
+    Guam,
+    Guatemala,
+    Guernsey,
+    Guinea,
+    GuineaBissau,
+    Guyana,
+    Haiti,
+    HeardIslandAndMcDonaldIslands,
+    Honduras,
+    HongKong,
+    Hungary,
+    Iceland,
+    India,
+    Indonesia,
+    Iran,
+    Iraq,
+    Ireland,
+    IsleOfMan,
+    Israel,
+    Italy,
+    IvoryCoast,
+    Jamaica,
+    Japan,
+    Jersey,
+    Jordan,
+    Kazakhstan,
+    Kenya,
+    Kiribati,
+    Kosovo,
+    Kuwait,
+    Kyrgyzstan,
+    Laos,
+    Latvia,
+    Lebanon,
+    Lesotho,
+    Liberia,
+    Libya,
+    Liechtenstein,
+    Lithuania,
+    Luxembourg,
+    Macao,
+    Madagascar,
+    Malawi,
+    Malaysia,
+    Maldives,
+    Mali,
+    Malta,
+    MarshallIslands,
+    Martinique,
+    Mauritania,
+    Mauritius,
+    Mayotte,
+    Mexico,
+    Micronesia,
+    Moldova,
+    Monaco,
+    Mongolia,
+    Montenegro,
+    Montserrat,
+    Morocco,
+    Mozambique,
+    Myanmar,
+    Namibia,
+    Nauru,
+    Nepal,
+    Netherlands,
+    NewCaledonia,
+    NewZealand,
+    Nicaragua,
+    Niger,
+    Nigeria,
+    Niue,
+    NorfolkIsland,
+    NorthKorea,
+    NorthMacedonia,
+    NorthernMarianaIslands,
+    Norway,
+    Oman,
+    Pakistan,
+    Palau,
+    Palestine,
+    Panama,
+    PapuaNewGuinea,
+    Paraguay,
+    Peru,
+    Philippines,
+    Pitcairn,
+    Poland,
+    Portugal,
+    PuertoRico,
+    Qatar,
+    Reunion,
+    Romania,
+    Russia,
+    Rwanda,
+    SaintBarthelemy,
+    SaintHelena,
+    SaintKittsAndNevis,
+    SaintLucia,
+    SaintMartin,
+    SaintPierreAndMiquelon,
+    SaintVincentAndTheGrenadines,
+    Samoa,
+    SanMarino,
+    SaoTomeAndPrincipe,
+    SaudiArabia,
+    Senegal,
+    Serbia,
+    Seychelles,
+    SierraLeone,
+    Singapore,
+    SintMaarten,
+    Slovakia,
+    Slovenia,
+    SolomonIslands,
+    Somalia,
+    SouthAfrica,
+    SouthGeorgiaAndTheSouthSandwichIslands,
+    SouthKorea,
+    SouthSudan,
+    Spain,
+    SriLanka,
+    Sudan,
+    Suriname,
+    SvalbardAndJanMayen,
+    Swaziland,
+    Sweden,
+    Switzerland,
+    Syria,
+    Taiwan,
+    Tajikistan,
+    Tanzania,
+    Thailand,
+    TimorLeste,
+    Togo,
+    Tokelau,
+    Tonga,
+    TrinidadAndTobago,
+    Tunisia,
+    Turkey,
+    Turkmenistan,
+    TurksAndCaicosIslands,
+    Tuvalu,
+    Uganda,
+    Ukraine,
+    UnitedArabEmirates,
+    UnitedKingdom,
+    UnitedStates,
+    UnitedStatesMinorOutlyingIslands,
+    Uruguay,
+    UsVirginIslands,
+    Uzbekistan,
+    Vanuatu,
+    VaticanCity,
+    Venezuela,
+    Vietnam,
+    WallisAndFutuna,
+    WesternSahara,
+    Yemen,
+    Zambia,
+    Zimbabwe,
+    LocationNotGiven,
+    Other(String),
+}
+
+/// Name shown by Scratch for users who didn't pick a country
+const LOCATION_NOT_GIVEN: &str = "Location not given";
+
+const COUNTRIES: &[(Country, &str, &str)] = &[
+    (Country::Afghanistan, "Afghanistan", "AF"),
+    (Country::AlandIslands, "Åland Islands", "AX"),
+    (Country::Albania, "Albania", "AL"),
+    (Country::Algeria, "Algeria", "DZ"),
+    (Country::AmericanSamoa, "American Samoa", "AS"),
+    (Country::Andorra, "Andorra", "AD"),
+    (Country::Angola, "Angola", "AO"),
+    (Country::Anguilla, "Anguilla", "AI"),
+    (Country::Antarctica, "Antarctica", "AQ"),
+    (Country::AntiguaAndBarbuda, "Antigua and Barbuda", "AG"),
+    (Country::Argentina, "Argentina", "AR"),
+    (Country::Armenia, "Armenia", "AM"),
+    (Country::Aruba, "Aruba", "AW"),
+    (Country::Australia, "Australia", "AU"),
+    (Country::Austria, "Austria", "AT"),
+    (Country::Azerbaijan, "Azerbaijan", "AZ"),
+    (Country::Bahamas, "Bahamas", "BS"),
+    (Country::Bahrain, "Bahrain", "BH"),
+    (Country::Bangladesh, "Bangladesh", "BD"),
+    (Country::Barbados, "Barbados", "BB"),
+    (Country::Belarus, "Belarus", "BY"),
+    (Country::Belgium, "Belgium", "BE"),
+    (Country::Belize, "Belize", "BZ"),
+    (Country::Benin, "Benin", "BJ"),
+    (Country::Bermuda, "Bermuda", "BM"),
+    (Country::Bhutan, "Bhutan", "BT"),
+    (Country::Bolivia, "Bolivia, Plurinational State of", "BO"),
+    (Country::BosniaAndHerzegovina, "Bosnia and Herzegovina", "BA"),
+    (Country::Botswana, "Botswana", "BW"),
+    (Country::BouvetIsland, "Bouvet Island", "BV"),
+    (Country::Brazil, "Brazil", "BR"),
+    (Country::BritishIndianOceanTerritory, "British Indian Ocean Territory", "IO"),
+    (Country::BritishVirginIslands, "Virgin Islands, British", "VG"),
+    (Country::Brunei, "Brunei Darussalam", "BN"),
+    (Country::Bulgaria, "Bulgaria", "BG"),
+    (Country::BurkinaFaso, "Burkina Faso", "BF"),
+    (Country::Burundi, "Burundi", "BI"),
+    (Country::Cambodia, "Cambodia", "KH"),
+    (Country::Cameroon, "Cameroon", "CM"),
+    (Country::Canada, "Canada", "CA"),
+    (Country::CapeVerde, "Cape Verde", "CV"),
+    (Country::CaribbeanNetherlands, "Bonaire, Sint Eustatius and Saba", "BQ"),
+    (Country::CaymanIslands, "Cayman Islands", "KY"),
+    (Country::CentralAfricanRepublic, "Central African Republic", "CF"),
+    (Country::Chad, "Chad", "TD"),
+    (Country::Chile, "Chile", "CL"),
+    (Country::China, "China", "CN"),
+    (Country::ChristmasIsland, "Christmas Island", "CX"),
+    (Country::CocosIslands, "Cocos (Keeling) Islands", "CC"),
+    (Country::Colombia, "Colombia", "CO"),
+    (Country::Comoros, "Comoros", "KM"),
+    (Country::Congo, "Congo", "CG"),
+    (Country::CookIslands, "Cook Islands", "CK"),
+    (Country::CostaRica, "Costa Rica", "CR"),
+    (Country::Croatia, "Croatia", "HR"),
+    (Country::Cuba, "Cuba", "CU"),
+    (Country::Curacao, "Curaçao", "CW"),
+    (Country::Cyprus, "Cyprus", "CY"),
+    (Country::CzechRepublic, "Czech Republic", "CZ"),
+    (Country::DemocraticRepublicOfTheCongo, "Congo, The Democratic Republic of the", "CD"),
+    (Country::Denmark, "Denmark", "DK"),
+    (Country::Djibouti, "Djibouti", "DJ"),
+    (Country::Dominica, "Dominica", "DM"),
+    (Country::DominicanRepublic, "Dominican Republic", "DO"),
+    (Country::Ecuador, "Ecuador", "EC"),
+    (Country::Egypt, "Egypt", "EG"),
+    (Country::ElSalvador, "El Salvador", "SV"),
+    (Country::EquatorialGuinea, "Equatorial Guinea", "GQ"),
+    (Country::Eritrea, "Eritrea", "ER"),
+    (Country::Estonia, "Estonia", "EE"),
+    (Country::Ethiopia, "Ethiopia", "ET"),
+    (Country::FalklandIslands, "Falkland Islands (Malvinas)", "FK"),
+    (Country::FaroeIslands, "Faroe Islands", "FO"),
+    (Country::Fiji, "Fiji", "FJ"),
+    (Country::Finland, "Finland", "FI"),
+    (Country::France, "France", "FR"),
+    (Country::FrenchGuiana, "French Guiana", "GF"),
+    (Country::FrenchPolynesia, "French Polynesia", "PF"),
+    (Country::FrenchSouthernTerritories, "French Southern Territories", "TF"),
+    (Country::Gabon, "Gabon", "GA"),
+    (Country::Gambia, "Gambia", "GM"),
+    (Country::Georgia, "Georgia", "GE"),
+    (Country::Germany, "Germany", "DE"),
+    (Country::Ghana, "Ghana", "GH"),
+    (Country::Gibraltar, "Gibraltar", "GI"),
+    (Country::Greece, "Greece", "GR"),
+    (Country::Greenland, "Greenland", "GL"),
+    (Country::Grenada, "Grenada", "GD"),
+    (Country::Guadeloupe, "Guadeloupe", "GP"),
+    (Country::Guam, "Guam", "GU"),
+    (Country::Guatemala, "Guatemala", "GT"),
+    (Country::Guernsey, "Guernsey", "GG"),
+    (Country::Guinea, "Guinea", "GN"),
+    (Country::GuineaBissau, "Guinea-Bissau", "GW"),
+    (Country::Guyana, "Guyana", "GY"),
+    (Country::Haiti, "Haiti", "HT"),
+    (Country::HeardIslandAndMcDonaldIslands, "Heard Island and McDonald Islands", "HM"),
+    (Country::Honduras, "Honduras", "HN"),
+    (Country::HongKong, "Hong Kong", "HK"),
+    (Country::Hungary, "Hungary", "HU"),
+    (Country::Iceland, "Iceland", "IS"),
+    (Country::India, "India", "IN"),
+    (Country::Indonesia, "Indonesia", "ID"),
+    (Country::Iran, "Iran, Islamic Republic of", "IR"),
+    (Country::Iraq, "Iraq", "IQ"),
+    (Country::Ireland, "Ireland", "IE"),
+    (Country::IsleOfMan, "Isle of Man", "IM"),
+    (Country::Israel, "Israel", "IL"),
+    (Country::Italy, "Italy", "IT"),
+    (Country::IvoryCoast, "Côte d'Ivoire", "CI"),
+    (Country::Jamaica, "Jamaica", "JM"),
+    (Country::Japan, "Japan", "JP"),
+    (Country::Jersey, "Jersey", "JE"),
+    (Country::Jordan, "Jordan", "JO"),
+    (Country::Kazakhstan, "Kazakhstan", "KZ"),
+    (Country::Kenya, "Kenya", "KE"),
+    (Country::Kiribati, "Kiribati", "KI"),
+    (Country::Kosovo, "Kosovo", "XK"),
+    (Country::Kuwait, "Kuwait", "KW"),
+    (Country::Kyrgyzstan, "Kyrgyzstan", "KG"),
+    (Country::Laos, "Lao People's Democratic Republic", "LA"),
+    (Country::Latvia, "Latvia", "LV"),
+    (Country::Lebanon, "Lebanon", "LB"),
+    (Country::Lesotho, "Lesotho", "LS"),
+    (Country::Liberia, "Liberia", "LR"),
+    (Country::Libya, "Libya", "LY"),
+    (Country::Liechtenstein, "Liechtenstein", "LI"),
+    (Country::Lithuania, "Lithuania", "LT"),
+    (Country::Luxembourg, "Luxembourg", "LU"),
+    (Country::Macao, "Macao", "MO"),
+    (Country::Madagascar, "Madagascar", "MG"),
+    (Country::Malawi, "Malawi", "MW"),
+    (Country::Malaysia, "Malaysia", "MY"),
+    (Country::Maldives, "Maldives", "MV"),
+    (Country::Mali, "Mali", "ML"),
+    (Country::Malta, "Malta", "MT"),
+    (Country::MarshallIslands, "Marshall Islands", "MH"),
+    (Country::Martinique, "Martinique", "MQ"),
+    (Country::Mauritania, "Mauritania", "MR"),
+    (Country::Mauritius, "Mauritius", "MU"),
+    (Country::Mayotte, "Mayotte", "YT"),
+    (Country::Mexico, "Mexico", "MX"),
+    (Country::Micronesia, "Micronesia, Federated States of", "FM"),
+    (Country::Moldova, "Moldova, Republic of", "MD"),
+    (Country::Monaco, "Monaco", "MC"),
+    (Country::Mongolia, "Mongolia", "MN"),
+    (Country::Montenegro, "Montenegro", "ME"),
+    (Country::Montserrat, "Montserrat", "MS"),
+    (Country::Morocco, "Morocco", "MA"),
+    (Country::Mozambique, "Mozambique", "MZ"),
+    (Country::Myanmar, "Myanmar", "MM"),
+    (Country::Namibia, "Namibia", "NA"),
+    (Country::Nauru, "Nauru", "NR"),
+    (Country::Nepal, "Nepal", "NP"),
+    (Country::Netherlands, "Netherlands", "NL"),
+    (Country::NewCaledonia, "New Caledonia", "NC"),
+    (Country::NewZealand, "New Zealand", "NZ"),
+    (Country::Nicaragua, "Nicaragua", "NI"),
+    (Country::Niger, "Niger", "NE"),
+    (Country::Nigeria, "Nigeria", "NG"),
+    (Country::Niue, "Niue", "NU"),
+    (Country::NorfolkIsland, "Norfolk Island", "NF"),
+    (Country::NorthKorea, "Korea, Democratic People's Republic of", "KP"),
+    (Country::NorthMacedonia, "Macedonia, The Former Yugoslav Republic of", "MK"),
+    (Country::NorthernMarianaIslands, "Northern Mariana Islands", "MP"),
+    (Country::Norway, "Norway", "NO"),
+    (Country::Oman, "Oman", "OM"),
+    (Country::Pakistan, "Pakistan", "PK"),
+    (Country::Palau, "Palau", "PW"),
+    (Country::Palestine, "Palestine, State of", "PS"),
+    (Country::Panama, "Panama", "PA"),
+    (Country::PapuaNewGuinea, "Papua New Guinea", "PG"),
+    (Country::Paraguay, "Paraguay", "PY"),
+    (Country::Peru, "Peru", "PE"),
+    (Country::Philippines, "Philippines", "PH"),
+    (Country::Pitcairn, "Pitcairn", "PN"),
+    (Country::Poland, "Poland", "PL"),
+    (Country::Portugal, "Portugal", "PT"),
+    (Country::PuertoRico, "Puerto Rico", "PR"),
+    (Country::Qatar, "Qatar", "QA"),
+    (Country::Reunion, "Réunion", "RE"),
+    (Country::Romania, "Romania", "RO"),
+    (Country::Russia, "Russian Federation", "RU"),
+    (Country::Rwanda, "Rwanda", "RW"),
+    (Country::SaintBarthelemy, "Saint Barthélemy", "BL"),
+    (Country::SaintHelena, "Saint Helena, Ascension and Tristan da Cunha", "SH"),
+    (Country::SaintKittsAndNevis, "Saint Kitts and Nevis", "KN"),
+    (Country::SaintLucia, "Saint Lucia", "LC"),
+    (Country::SaintMartin, "Saint Martin (French part)", "MF"),
+    (Country::SaintPierreAndMiquelon, "Saint Pierre and Miquelon", "PM"),
+    (Country::SaintVincentAndTheGrenadines, "Saint Vincent and the Grenadines", "VC"),
+    (Country::Samoa, "Samoa", "WS"),
+    (Country::SanMarino, "San Marino", "SM"),
+    (Country::SaoTomeAndPrincipe, "Sao Tome and Principe", "ST"),
+    (Country::SaudiArabia, "Saudi Arabia", "SA"),
+    (Country::Senegal, "Senegal", "SN"),
+    (Country::Serbia, "Serbia", "RS"),
+    (Country::Seychelles, "Seychelles", "SC"),
+    (Country::SierraLeone, "Sierra Leone", "SL"),
+    (Country::Singapore, "Singapore", "SG"),
+    (Country::SintMaarten, "Sint Maarten (Dutch part)", "SX"),
+    (Country::Slovakia, "Slovakia", "SK"),
+    (Country::Slovenia, "Slovenia", "SI"),
+    (Country::SolomonIslands, "Solomon Islands", "SB"),
+    (Country::Somalia, "Somalia", "SO"),
+    (Country::SouthAfrica, "South Africa", "ZA"),
+    (Country::SouthGeorgiaAndTheSouthSandwichIslands, "South Georgia and the South Sandwich Islands", "GS"),
+    (Country::SouthKorea, "Korea, Republic of", "KR"),
+    (Country::SouthSudan, "South Sudan", "SS"),
+    (Country::Spain, "Spain", "ES"),
+    (Country::SriLanka, "Sri Lanka", "LK"),
+    (Country::Sudan, "Sudan", "SD"),
+    (Country::Suriname, "Suriname", "SR"),
+    (Country::SvalbardAndJanMayen, "Svalbard and Jan Mayen", "SJ"),
+    (Country::Swaziland, "Swaziland", "SZ"),
+    (Country::Sweden, "Sweden", "SE"),
+    (Country::Switzerland, "Switzerland", "CH"),
+    (Country::Syria, "Syrian Arab Republic", "SY"),
+    (Country::Taiwan, "Taiwan", "TW"),
+    (Country::Tajikistan, "Tajikistan", "TJ"),
+    (Country::Tanzania, "Tanzania, United Republic of", "TZ"),
+    (Country::Thailand, "Thailand", "TH"),
+    (Country::TimorLeste, "Timor-Leste", "TL"),
+    (Country::Togo, "Togo", "TG"),
+    (Country::Tokelau, "Tokelau", "TK"),
+    (Country::Tonga, "Tonga", "TO"),
+    (Country::TrinidadAndTobago, "Trinidad and Tobago", "TT"),
+    (Country::Tunisia, "Tunisia", "TN"),
+    (Country::Turkey, "Turkey", "TR"),
+    (Country::Turkmenistan, "Turkmenistan", "TM"),
+    (Country::TurksAndCaicosIslands, "Turks and Caicos Islands", "TC"),
+    (Country::Tuvalu, "Tuvalu", "TV"),
+    (Country::Uganda, "Uganda", "UG"),
+    (Country::Ukraine, "Ukraine", "UA"),
+    (Country::UnitedArabEmirates, "United Arab Emirates", "AE"),
+    (Country::UnitedKingdom, "United Kingdom", "GB"),
+    (Country::UnitedStates, "United States", "US"),
+    (Country::UnitedStatesMinorOutlyingIslands, "United States Minor Outlying Islands", "UM"),
+    (Country::Uruguay, "Uruguay", "UY"),
+    (Country::UsVirginIslands, "Virgin Islands, U.S.", "VI"),
+    (Country::Uzbekistan, "Uzbekistan", "UZ"),
+    (Country::Vanuatu, "Vanuatu", "VU"),
+    (Country::VaticanCity, "Holy See (Vatican City State)", "VA"),
+    (Country::Venezuela, "Venezuela, Bolivarian Republic of", "VE"),
+    (Country::Vietnam, "Viet Nam", "VN"),
+    (Country::WallisAndFutuna, "Wallis and Futuna", "WF"),
+    (Country::WesternSahara, "Western Sahara", "EH"),
+    (Country::Yemen, "Yemen", "YE"),
+    (Country::Zambia, "Zambia", "ZM"),
+    (Country::Zimbabwe, "Zimbabwe", "ZW"),
+];
+
+impl Country {
+    pub fn from_name(name: &str) -> Self {
+        if name == LOCATION_NOT_GIVEN {
+            return Self::LocationNotGiven
+        }
+        COUNTRIES.iter()
+        .find(|(_, known, _)| *known == name)
+        .map(|(country, ..)| country.clone())
+        .unwrap_or_else(|| Self::Other(name.to_owned()))
+    }
+
+    /// Looks up country by ISO 3166-1 alpha-2 code, compared case-insensitively
+    pub fn from_code(code: &str) -> Option<Self> {
+        COUNTRIES.iter()
+        .find(|(.., known)| known.eq_ignore_ascii_case(code))
+        .map(|(country, ..)| country.clone())
+    }
+
+    /// Name as shown by Scratch
+    pub fn name(&self) -> &str {
+        match self {
+            Self::LocationNotGiven => LOCATION_NOT_GIVEN,
+            Self::Other(name) => name,
+            _ => self.entry().map(|(_, name, _)| *name).unwrap_or_default()
+        }
+    }
+
+    /// ISO 3166-1 alpha-2 code, `None` for [`Country::LocationNotGiven`] and [`Country::Other`]
+    pub fn code(&self) -> Option<&'static str> {
+        self.entry().map(|(.., code)| *code)
+    }
+
+    fn entry(&self) -> Option<&'static (Country, &'static str, &'static str)> {
+        COUNTRIES.iter().find(|(country, ..)| country == self)
+    }
+}
+
+impl From<&str> for Country {
+    fn from(name: &str) -> Self {
+        Self::from_name(name)
+    }
+}
+
+impl From<String> for Country {
+    fn from(name: String) -> Self {
+        Self::from_name(&name)
+    }
+}
+
+impl std::fmt::Display for Country {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Serialize for Country {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Country {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self::from_name(&String::deserialize(deserializer)?))
+    }
+}
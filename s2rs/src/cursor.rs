@@ -1,5 +1,7 @@
 
-#[derive(Default, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Cursor {
     pub start: usize,
     pub end: Option<usize>,
@@ -54,6 +56,26 @@ impl Cursor {
     pub fn get_limit(&self) -> Option<usize> {
         self.end.map(|end| end - self.start)
     }
+
+    /// Splits cursor into `count` consecutive pages of `limit` items each, stopping at `end` if there is one
+    pub fn pages(&self, limit: usize, count: usize) -> Vec<Self> {
+        let mut cursor = self.clone();
+        let mut result = Vec::new();
+        while limit > 0 && result.len() < count && cursor.can_progress() {
+            result.push(cursor.progress(limit));
+        }
+        result
+    }
+
+    /// Same as [`Cursor::pages`], but pages end at multiples of `limit`, so the first one is shorter when `start` is in the middle of a page
+    pub fn aligned_pages(&self, limit: usize, count: usize) -> Vec<Self> {
+        let mut cursor = self.clone();
+        let mut result = Vec::new();
+        while limit > 0 && result.len() < count && cursor.can_progress() {
+            result.push(cursor.progress(limit - cursor.start % limit));
+        }
+        result
+    }
 }
 
 impl From<(usize, usize)> for Cursor {
@@ -0,0 +1,101 @@
+//! Dates returned by Scratch
+//! - With crate feature `time` dates are parsed into [`chrono::DateTime<Utc>`], otherwise they stay as returned
+#[cfg(feature = "time")] use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+#[cfg(feature = "time")] use serde::{Deserialize, Serialize};
+
+/// Date as returned by Scratch
+#[cfg(not(feature = "time"))]
+pub type Date = String;
+
+/// Parses `data` into [`Date`]
+#[cfg(not(feature = "time"))]
+pub fn parse(data: String) -> Date {
+    data
+}
+
+/// Parses `data` into [`Date`]
+#[cfg(feature = "time")]
+pub fn parse(data: String) -> Date {
+    Date::parse(&data)
+}
+
+/// Parsed date
+/// - `Raw` keeps the original string when its format isn't recognized
+/// # Examples
+/// ```
+/// # tokio_test::block_on(async {
+/// # use s2rs::session::Session;
+/// # let session = Session::new("YourUsername");
+/// let meta = session.user("griffpatch").meta().await.unwrap();
+/// if let Some(joined) = meta.history.joined.date_time() {
+///     dbg![joined.timestamp()];
+/// }
+/// # })
+/// ```
+#[cfg(feature = "time")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Date {
+    Parsed(DateTime<Utc>),
+    Raw(String),
+}
+
+#[cfg(feature = "time")]
+impl Date {
+    pub fn parse(data: &str) -> Self {
+        if let Ok(date) = DateTime::parse_from_rfc3339(data) {
+            return Self::Parsed(date.with_timezone(&Utc))
+        }
+        for format in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"] {
+            if let Ok(date) = NaiveDateTime::parse_from_str(data, format) {
+                return Self::Parsed(Utc.from_utc_datetime(&date))
+            }
+        }
+        Self::Raw(data.to_owned())
+    }
+
+    /// Unix time in milliseconds
+    pub fn from_millis(millis: u64) -> Self {
+        match i64::try_from(millis).ok().and_then(|millis| Utc.timestamp_millis_opt(millis).single()) {
+            Some(date) => Self::Parsed(date),
+            None => Self::Raw(millis.to_string())
+        }
+    }
+
+    pub fn date_time(&self) -> Option<&DateTime<Utc>> {
+        match self {
+            Self::Parsed(date) => Some(date),
+            Self::Raw(_) => None
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<String> for Date {
+    fn from(value: String) -> Self {
+        Self::parse(&value)
+    }
+}
+
+#[cfg(feature = "time")]
+impl std::fmt::Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parsed(date) => write!(f, "{}", date.to_rfc3339()),
+            Self::Raw(data) => write!(f, "{data}")
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self::parse(&String::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(feature = "time")]
+impl Serialize for Date {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.collect_str(self)
+    }
+}
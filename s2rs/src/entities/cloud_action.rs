@@ -1,19 +1,30 @@
 use std::sync::Arc;
+use s2rs_derive::Forwarder;
 use crate::api::{self, Api};
 use super::User;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CloudAction {
     pub by: Arc<User>,
+    /// Unix time in milliseconds, used for ordering and exports
     pub timestamp: u64,
+    /// `timestamp` converted to a date
+    /// - `Requires crate feature: 'time'`
+    #[cfg(feature = "time")]
+    pub date: crate::Date,
     pub event: CloudActionEvent,
 }
 
 impl CloudAction {
+    pub const CSV_HEADER: &'static str = "timestamp,user,verb,name,value";
+
     pub fn new(data: api::CloudAction, api: Arc<Api>) -> Arc<Self> {
         Arc::new(Self {
             by: User::new(data.by_name, api),
             timestamp: data.timestamp,
+            #[cfg(feature = "time")]
+            date: data.date,
             event: CloudActionEvent::new(data.event)
         })
     }
@@ -21,9 +32,50 @@ impl CloudAction {
     pub fn vec_new(data: Vec<api::CloudAction>, api: Arc<Api>) -> Vec<Arc<Self>> {
         data.into_iter().map(|data| Self::new(data, api.clone())).collect()
     }
+
+    /// Row matching [`CloudAction::CSV_HEADER`]
+    pub fn to_csv(&self) -> String {
+        fn escape(value: &str) -> String {
+            if value.contains([',', '"', '\n', '\r']) {
+                format!["\"{}\"", value.replace('"', "\"\"")]
+            } else {
+                value.to_owned()
+            }
+        }
+        [
+            self.timestamp.to_string(),
+            escape(&self.by.name),
+            self.event.verb().to_owned(),
+            escape(self.event.name()),
+            escape(self.event.value().unwrap_or_default()),
+        ].join(",")
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "timestamp": self.timestamp,
+            "user": self.by.name.as_str(),
+            "verb": self.event.verb(),
+            "name": self.event.name(),
+            "value": self.event.value(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloudExportFormat {
+    Csv,
+    JsonLines,
+}
+
+#[derive(Forwarder, Debug)]
+pub enum ExportCloudActivityError {
+    #[forward] Get(api::GetProjectCloudActivityError),
+    #[forward] Io(std::io::Error),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum CloudActionEvent {
     Set {
         name: String,
@@ -41,4 +93,27 @@ impl CloudActionEvent {
             api::CloudActionEvent::Set { name, value } => Self::Set { name, value }
         }
     }
+
+    /// Name of the variable
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Set { name, .. } | Self::Create(name) | Self::Delete(name) => name
+        }
+    }
+
+    pub fn value(&self) -> Option<&str> {
+        match self {
+            Self::Set { value, .. } => Some(value),
+            _ => None
+        }
+    }
+
+    /// Verb used by the cloud log
+    pub fn verb(&self) -> &'static str {
+        match self {
+            Self::Set { .. } => "set_var",
+            Self::Create(_) => "create_var",
+            Self::Delete(_) => "del_var",
+        }
+    }
 }
\ No newline at end of file
@@ -1,13 +1,15 @@
 use super::{UserWithId, User, ProjectWithTitle, StudioWithTitle};
 use std::sync::Arc;
 use crate::api::{Api, self};
+use crate::date::Date;
 
 // region: FollowingAction
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FollowingAction {
     pub id: u64,
     pub actor: Arc<UserWithId>,
-    pub created_at: String,
+    pub created_at: Date,
     pub event: FollowingActionEvent,
 }
 
@@ -28,7 +30,8 @@ impl FollowingAction {
 // endregion: FollowingAction
 
 // region: FollowingActionEvent
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum FollowingActionEvent {
     FollowUser(Arc<UserWithId>),
     FollowStudio(Arc<StudioWithTitle>),
@@ -46,6 +49,10 @@ pub enum FollowingActionEvent {
     PromoteStudio {
         to: Arc<UserWithId>,
         this: Arc<StudioWithTitle>
+    },
+    Unknown {
+        event_type: String,
+        raw: serde_json::Value,
     }
 }
 
@@ -85,7 +92,9 @@ impl FollowingActionEvent {
 
             api::FollowingActionEvent::ShareProject { title, id } => Self::ShareProject(
                 ProjectWithTitle::new(title, id, api)
-            )
+            ),
+
+            api::FollowingActionEvent::Unknown { event_type, raw } => Self::Unknown { event_type, raw }
         }
     }
 }
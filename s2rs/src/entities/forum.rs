@@ -1,13 +1,16 @@
 
 use std::sync::Arc;
-use crate::{Api, api};
+use crate::{Api, api, date::Date, bbcode::BBCode};
 #[cfg(feature = "time")] use chrono::{DateTime, Utc};
 use derivative::Derivative;
-#[cfg(feature = "rss")] use super::User;
+use super::User;
+#[cfg(all(feature = "stream", feature = "html"))] use crate::cursor::Cursor;
+#[cfg(all(feature = "stream", feature = "html"))] use super::{stream::GeneralStream, forum_stream::ForumTopicPosts};
 
 // region: ForumTopicRss
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[cfg(feature = "time")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ForumTopicRss {
     pub this: Arc<ForumTopic>,
     pub title: String,
@@ -29,8 +32,9 @@ impl ForumTopicRss {
 // endregion: ForumTopicRss
 
 // region: ForumTopicRssPost
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[cfg(feature = "time")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ForumTopicRssPost {
     pub this: Arc<ForumPost>,
     pub created_at: DateTime<Utc>,
@@ -55,12 +59,73 @@ impl ForumTopicRssPost {
 }
 // endregion: ForumTopicRssPost
 
+// region: ForumTopicPost
+/// Post scraped from a page of [`ForumTopic`]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ForumTopicPost {
+    pub this: Arc<ForumPost>,
+    pub topic: Arc<ForumTopic>,
+    pub index: Option<u32>,
+    pub author: Arc<User>,
+    pub author_rank: Option<String>,
+    pub author_post_count: Option<u64>,
+    pub author_avatar_url: Option<String>,
+    pub created_at: Date,
+    pub content: String,
+    pub signature: Option<String>,
+    pub edit: Option<ForumPostEdit>,
+}
+
+pub type ForumTopicPostEntry = Result<Arc<ForumTopicPost>, api::ParseForumError>;
+
+impl ForumTopicPost {
+    pub fn with_topic(data: api::ForumTopicPost, topic: Arc<ForumTopic>, api: Arc<Api>) -> Arc<Self> {
+        Arc::new(Self {
+            this: ForumPost::new(data.id, api.clone()),
+            topic,
+            index: data.index,
+            author: User::new(data.author_name, api.clone()),
+            author_rank: data.author_rank,
+            author_post_count: data.author_post_count,
+            author_avatar_url: data.author_avatar_url,
+            created_at: data.created_at,
+            content: data.content,
+            signature: data.signature,
+            edit: data.edit.map(|edit| ForumPostEdit::new(edit, api)),
+        })
+    }
+
+    pub fn vec_with_topic(data: Vec<Result<api::ForumTopicPost, api::ParseForumError>>, topic: Arc<ForumTopic>, api: Arc<Api>) -> Vec<ForumTopicPostEntry> {
+        data.into_iter().map(|data| Ok(Self::with_topic(data?, topic.clone(), api.clone()))).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ForumPostEdit {
+    pub author: Arc<User>,
+    pub edited_at: Date,
+}
+
+impl ForumPostEdit {
+    pub fn new(data: api::ForumPostEdit, api: Arc<Api>) -> Self {
+        Self {
+            author: User::new(data.author_name, api),
+            edited_at: data.edited_at,
+        }
+    }
+}
+// endregion: ForumTopicPost
+
 // region: ForumTopic
-#[derive(Derivative)]
+#[derive(Derivative, Clone)]
 #[derivative(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ForumTopic {
     pub id: u64,
     #[derivative(Debug = "ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     api: Arc<Api>
 }
 
@@ -77,17 +142,46 @@ impl ForumTopic {
         Ok(ForumTopicRss::with_this(self.api.forum_topic_rss(self.id).await?, self.clone(), self.api.clone()))
     }
 
+    /// Posts scraped from the topic pages
+    /// - Posts which fail to parse are left out and collected in [`ForumTopicPosts::skipped`]
+    /// # Examples
+    /// ```
+    /// # tokio_test::block_on(async {
+    /// # use s2rs::session::Session;
+    /// use s2rs::entities::Stream;
+    /// # let session = Session::new("YourUsername");
+    /// let topic = session.forum_topic(6369);
+    /// let mut posts = topic.posts((0, 40));
+    /// for post in posts.collect().await.unwrap() {
+    ///     dbg![ &post.author.name, &post.content ];
+    /// }
+    /// dbg![ posts.gen().skipped.take() ];
+    /// # })
+    /// ```
+    #[cfg(all(feature = "stream", feature = "html"))]
+    pub fn posts(self: &Arc<Self>, cursor: impl Into<Cursor>) -> GeneralStream<ForumTopicPosts> {
+        GeneralStream::with_this(ForumTopicPosts::default(), cursor.into(), self.clone(), self.api.clone())
+    }
+
+    /// One page of posts in the topic, `page` starts at 1
+    #[cfg(feature = "html")]
+    pub async fn posts_page(self: &Arc<Self>, page: u32) -> Result<Vec<ForumTopicPostEntry>, api::GetForumPageError> {
+        Ok(ForumTopicPost::vec_with_topic(self.api.forum_topic_posts(self.id, Some(page)).await?, self.clone(), self.api.clone()))
+    }
+
     pub async fn send_post(&self, content: &str) -> api::Result<()> {
         self.api.send_forum_post(self.id, content).await
     }
 }
 // endregion: ForumTopic
 
-#[derive(Derivative)]
+#[derive(Derivative, Clone)]
 #[derivative(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ForumPost {
     pub id: u64,
     #[derivative(Debug = "ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     api: Arc<Api>
 }
 
@@ -103,6 +197,11 @@ impl ForumPost {
         self.api.forum_post_content(self.id).await
     }
 
+    /// Source of the post parsed as [`BBCode`]
+    pub async fn bbcode(&self) -> api::Result<BBCode> {
+        Ok(BBCode::parse(&self.content().await?))
+    }
+
     pub async fn edit(&self, content: &str) -> api::Result<()> {
         self.api.edit_forum_post(self.id, content).await
     }
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+use crate::api::{self, Api};
+use super::stream::{GeneralStreamResult, GeneralStreamGen, SkippedEntries, page_of, trim_page};
+use async_trait::async_trait;
+use crate::cursor::Cursor;
+use super::{ForumTopic, ForumTopicPost};
+
+// region: ForumTopicPosts
+#[derive(Clone, Default)] pub struct ForumTopicPosts {
+    pub skipped: SkippedEntries<api::ParseForumError>,
+}
+#[async_trait] impl GeneralStreamGen for ForumTopicPosts {
+    type Data = ForumTopicPost;
+    type Error = api::GetForumPageError;
+    type This = ForumTopic;
+    const LIMIT: usize = api::FORUM_TOPIC_PAGE_LIMIT;
+    const PAGED: bool = true;
+    async fn gen(&self, cursor: Cursor, this: &Arc<Self::This>, api: &Arc<Api>) -> GeneralStreamResult<Self> {
+        Ok(self.gen_counted(cursor, this, api).await?.0)
+    }
+    async fn gen_counted(&self, cursor: Cursor, this: &Arc<Self::This>, api: &Arc<Api>) -> Result<(Vec<Arc<Self::Data>>, usize), Self::Error> {
+        let data = api.forum_topic_posts(this.id, Some(page_of(&cursor, Self::LIMIT))).await?;
+        let data = ForumTopicPost::vec_with_topic(trim_page(data, &cursor, Self::LIMIT), this.clone(), api.clone());
+        let len = data.len();
+        Ok((self.skipped.split(data), len))
+    }
+}
+// endregion: ForumTopicPosts
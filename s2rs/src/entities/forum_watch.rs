@@ -0,0 +1,222 @@
+use std::{sync::Arc, collections::{HashMap, VecDeque}, path::PathBuf};
+use async_trait::async_trait;
+use derivative::Derivative;
+use futures_util::Stream;
+use s2rs_derive::Forwarder;
+use crate::api::{self, Api};
+#[cfg(feature = "html")] use crate::api::ForumCategory;
+use super::{ForumTopic, ForumTopicRssPost, watch::{AdaptiveInterval, Poller, watch_stream}};
+
+/// Id of the newest seen post of each watched topic
+pub type ForumSeenPosts = HashMap<u64, u64>;
+
+// region: ForumWatchCheckpoint
+/// Receives seen post ids every time they change, so watching can be resumed later
+/// - Pass the saved ids back as [`ForumWatchOptions::seen`]
+pub trait ForumWatchCheckpoint: Send + Sync {
+    fn save(&self, seen: &ForumSeenPosts);
+}
+
+/// Persists seen post ids as JSON file
+#[derive(Debug, Clone)]
+pub struct ForumWatchFile {
+    pub path: PathBuf,
+}
+
+impl ForumWatchFile {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into()
+        }
+    }
+
+    /// Last saved ids, `None` if nothing was saved yet or the file can't be read
+    pub fn load(&self) -> Option<ForumSeenPosts> {
+        serde_json::from_slice(&std::fs::read(&self.path).ok()?).ok()
+    }
+}
+
+impl ForumWatchCheckpoint for ForumWatchFile {
+    /// - Write failures are ignored, watching keeps going and the previous ids stay on disk
+    fn save(&self, seen: &ForumSeenPosts) {
+        if let Ok(data) = serde_json::to_vec(seen) {
+            let _ = std::fs::write(&self.path, data);
+        }
+    }
+}
+// endregion: ForumWatchCheckpoint
+
+// region: ForumWatch
+#[derive(Derivative, Clone, Default)]
+#[derivative(Debug)]
+pub struct ForumWatchOptions {
+    pub interval: AdaptiveInterval,
+    /// Topics whose posts are watched
+    pub topics: Vec<u64>,
+    /// Categories whose first page is checked on every poll, topics bumped there are checked for new posts too
+    /// - Topics created after watching started emit all of their posts
+    #[cfg(feature = "html")]
+    pub categories: Vec<ForumCategory>,
+    /// Newest handled post of each topic, posts after it are emitted on the first poll
+    /// - Topics missing here only emit posts made after watching started
+    pub seen: ForumSeenPosts,
+    #[derivative(Debug = "ignore")]
+    pub checkpoint: Option<Arc<dyn ForumWatchCheckpoint>>,
+}
+
+impl ForumWatchOptions {
+    pub fn new(topics: Vec<u64>) -> Self {
+        Self {
+            topics,
+            ..Default::default()
+        }
+    }
+}
+
+/// New post in one of the watched topics
+#[derive(Debug, Clone)]
+pub struct ForumWatchPost {
+    pub topic: Arc<ForumTopic>,
+    pub topic_title: String,
+    pub post: Arc<ForumTopicRssPost>,
+}
+
+#[derive(Forwarder, Debug)]
+pub enum ForumWatchError {
+    #[forward] Rss(api::GetForumTopicRssError),
+    #[forward] Category(api::GetForumPageError),
+}
+
+struct ForumWatch {
+    api: Arc<Api>,
+    options: ForumWatchOptions,
+    pending: VecDeque<Result<ForumWatchPost, ForumWatchError>>,
+}
+
+#[async_trait]
+impl Poller for ForumWatch {
+    type Item = ForumWatchPost;
+    type Error = ForumWatchError;
+
+    /// - Errors of single topics or categories are queued, so the other ones still get polled
+    async fn poll(&mut self, first: bool) -> Result<(), ForumWatchError> {
+        let seen = self.options.seen.clone();
+
+        let bumped = self.poll_categories(first).await;
+        let topics = self.options.topics.iter().map(|id| (*id, false)).chain(bumped).collect::<Vec<_>>();
+        for (id, is_new) in topics {
+            if let Err(error) = self.poll_topic(id, is_new).await {
+                self.pending.push_back(Err(error));
+            }
+        }
+
+        if self.options.seen != seen {
+            self.save_checkpoint();
+        }
+        if self.pending.iter().any(Result::is_ok) {
+            self.options.interval.reset();
+        } else {
+            self.options.interval.back_off();
+        }
+        Ok(())
+    }
+
+    /// - Seen post of the topic only advances once its post is yielded, so unhandled posts are emitted again after resuming
+    fn pending(&mut self) -> Option<Result<ForumWatchPost, ForumWatchError>> {
+        let item = self.pending.pop_front()?;
+        if let Ok(post) = &item {
+            let seen = self.options.seen.entry(post.topic.id).or_default();
+            *seen = (*seen).max(post.post.this.id);
+            self.save_checkpoint();
+        }
+        Some(item)
+    }
+
+    fn interval(&mut self) -> &mut AdaptiveInterval {
+        &mut self.options.interval
+    }
+}
+
+impl ForumWatch {
+    fn save_checkpoint(&self) {
+        if let Some(checkpoint) = &self.options.checkpoint {
+            checkpoint.save(&self.options.seen);
+        }
+    }
+
+    /// - When topic wasn't seen before, its posts are only emitted if it `is_new`
+    async fn poll_topic(&mut self, id: u64, is_new: bool) -> Result<(), ForumWatchError> {
+        let data = self.api.forum_topic_rss(id).await?;
+        let Some(newest) = data.posts.iter().map(|post| post.id).max() else {
+            return Ok(())
+        };
+
+        let last = match self.options.seen.get(&id) {
+            Some(last) => Some(*last),
+            None if is_new => Some(0),
+            None => None
+        };
+        let Some(last) = last else {
+            self.options.seen.insert(id, newest);
+            return Ok(())
+        };
+        let topic = ForumTopic::new(id, self.api.clone());
+        let mut posts: Vec<_> = data.posts.into_iter().filter(|post| post.id > last).collect();
+        posts.sort_by_key(|post| post.id);
+        for post in ForumTopicRssPost::vec_new(posts, self.api.clone()) {
+            self.pending.push_back(Ok(ForumWatchPost {
+                topic: topic.clone(),
+                topic_title: data.title.clone(),
+                post,
+            }));
+        }
+        Ok(())
+    }
+
+    /// Topics of watched categories which were bumped since the last poll, along with whether they are new
+    /// - On the first poll unseen topics are only remembered
+    /// - Seen topics whose last post couldn't be read from the category page are skipped, they can't be told apart from idle ones
+    #[cfg(feature = "html")]
+    async fn poll_categories(&mut self, first: bool) -> Vec<(u64, bool)> {
+        let mut result = Vec::new();
+        for category in self.options.categories.clone() {
+            let topics = match self.api.forum_category_topics(category, Some(1)).await {
+                Ok(topics) => topics,
+                Err(error) => {
+                    self.pending.push_back(Err(error.into()));
+                    continue
+                }
+            };
+            for topic in topics.into_iter().flatten() {
+                if self.options.topics.contains(&topic.id) || result.iter().any(|(id, _)| *id == topic.id) {
+                    continue
+                }
+                match (self.options.seen.get(&topic.id), topic.last_post_id) {
+                    (Some(seen), Some(last)) if *seen >= last => {},
+                    (Some(_), None) => {},
+                    (None, Some(last)) if first => { self.options.seen.insert(topic.id, last); },
+                    // newest post is taken from the topic itself, without emitting anything
+                    (None, None) if first => result.push((topic.id, false)),
+                    (seen, _) => result.push((topic.id, seen.is_none()))
+                }
+            }
+        }
+        result
+    }
+
+    #[cfg(not(feature = "html"))]
+    async fn poll_categories(&mut self, _first: bool) -> Vec<(u64, bool)> {
+        Vec::new()
+    }
+}
+
+pub(crate) fn watch(api: Arc<Api>, options: ForumWatchOptions) -> impl Stream<Item = Result<ForumWatchPost, ForumWatchError>> + Send {
+    let state = ForumWatch {
+        api,
+        options,
+        pending: VecDeque::new(),
+    };
+
+    watch_stream(state)
+}
+// endregion: ForumWatch
@@ -3,7 +3,8 @@ use std::sync::Arc;
 use crate::{api, Api};
 use super::{ProjectWithTitle, User, StudioWithTitle};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FrontPage {
     pub new_projects: Vec<FrontPageProject>,
     pub featured_studios: Vec<FrontPageFeaturedStudio>,
@@ -39,7 +40,8 @@ impl FrontPage {
 // }
 
 // region: structures
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FrontPageProject {
     pub this: Arc<ProjectWithTitle>,
     pub author: Arc<User>,
@@ -62,7 +64,8 @@ impl FrontPageProject {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FrontPageMostRemixedProject {
     pub this: Arc<ProjectWithTitle>,
     pub author: Arc<User>,
@@ -87,7 +90,8 @@ impl FrontPageMostRemixedProject {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FrontPageDesignStudioProject {
     pub this: Arc<ProjectWithTitle>,
     pub author: Arc<User>,
@@ -114,7 +118,8 @@ impl FrontPageDesignStudioProject {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FrontPageCuratedProject {
     pub this: Arc<ProjectWithTitle>,
     pub author: Arc<User>,
@@ -139,7 +144,8 @@ impl FrontPageCuratedProject {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FrontPageFeaturedStudio {
     pub this: Arc<StudioWithTitle>,
     pub thumbnail_url: String,
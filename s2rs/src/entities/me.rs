@@ -1,9 +1,9 @@
 use std::sync::Arc;
 use crate::{Api, api::{UserInfo, FeaturedLabel, self}};
-use super::{User, FrontPage};
+use super::{User, FrontPage, StuffProject};
 use derivative::Derivative;
 use s2rs_derive::deref;
-#[cfg(feature = "stream")] use super::{stream::GeneralStream, MeProjectsLovedByFollowing, MeProjectsSharedByFollowing, MeViewedProjects};
+#[cfg(feature = "stream")] use super::{stream::GeneralStream, MeProjectsLovedByFollowing, MeProjectsSharedByFollowing, MeViewedProjects, MeStuffProjects, MeStuffStudios};
 #[cfg(feature = "stream")] use crate::Cursor;
 
 #[deref(this)]
@@ -54,6 +54,37 @@ impl Me {
         self.api.projects_count().await
     }
 
+    /// Permanently deletes every project in trash
+    /// - With `dry_run` nothing is deleted, returned projects are the ones which would be
+    /// # Examples
+    /// ```
+    /// # tokio_test::block_on(async {
+    /// # use s2rs::session::Session;
+    /// # let session = Session::new("YourUsername");
+    /// # let me = session.me();
+    /// for project in me.empty_trash(true).await.unwrap() {
+    ///     println!("would delete {}", project.title);
+    /// }
+    /// # })
+    /// ```
+    pub async fn empty_trash(&self, dry_run: bool) -> api::Result<Vec<Arc<StuffProject>>> {
+        let mut trashed = Vec::new();
+        for page in 1.. {
+            let data = self.api.stuff_trashed(page, api::StuffSort::default(), api::SortDirection::default()).await?;
+            let full = data.len() == api::STUFF_PAGE_LIMIT;
+            trashed.append(&mut StuffProject::vec_new(data, self.api.clone()));
+            if !full {
+                break
+            }
+        }
+        if !dry_run {
+            for project in &trashed {
+                project.delete().await?;
+            }
+        }
+        Ok(trashed)
+    }
+
     #[cfg(feature = "file")]
     pub async fn set_icon<B>(&self, buffer: B) -> api::Result<()>
     where B: Into<std::borrow::Cow<'static, [u8]>> {
@@ -79,4 +110,29 @@ impl Me {
     pub fn viewed_projects(self: &Arc<Self>, cursor: impl Into<Cursor>) -> GeneralStream<MeViewedProjects> {
         GeneralStream::with_this(MeViewedProjects, cursor.into(), self.clone(), self.api.clone())
     }
+
+    /// Own projects from My Stuff
+    /// # Examples
+    /// ```
+    /// # tokio_test::block_on(async {
+    /// # use s2rs::session::Session;
+    /// use s2rs::{api::{StuffFilter, StuffSort, SortDirection}, entities::Stream};
+    /// # let session = Session::new("YourUsername");
+    /// # let me = session.me();
+    /// let mut projects = me.stuff_projects(StuffFilter::Shared, StuffSort::Views, SortDirection::Descending, (0, None));
+    /// for project in projects.collect().await.unwrap() {
+    ///     dbg![ &project.title, project.view_count ];
+    /// }
+    /// # })
+    /// ```
+    #[cfg(feature = "stream")]
+    pub fn stuff_projects(self: &Arc<Self>, filter: api::StuffFilter, sort: api::StuffSort, direction: api::SortDirection, cursor: impl Into<Cursor>) -> GeneralStream<MeStuffProjects> {
+        GeneralStream::with_this(MeStuffProjects { filter, sort, direction }, cursor.into(), self.clone(), self.api.clone())
+    }
+
+    /// Own studios from My Stuff
+    #[cfg(feature = "stream")]
+    pub fn stuff_studios(self: &Arc<Self>, sort: api::StuffSort, direction: api::SortDirection, cursor: impl Into<Cursor>) -> GeneralStream<MeStuffStudios> {
+        GeneralStream::with_this(MeStuffStudios { sort, direction }, cursor.into(), self.clone(), self.api.clone())
+    }
 }
\ No newline at end of file
@@ -1,16 +1,16 @@
 use async_trait::async_trait;
 use std::sync::Arc;
 use crate::{api, Cursor, Api};
-use super::{stream::{GeneralStreamGen, GeneralStreamResult}, Project2, Me};
+use super::{stream::{GeneralStreamGen, GeneralStreamResult, page_of, trim_page}, ProjectMeta, Me, StuffProject, StuffStudio};
 
 // region: MeProjectsLovedByFollowing
 #[derive(Clone)] pub struct MeProjectsLovedByFollowing;
 #[async_trait] impl GeneralStreamGen for MeProjectsLovedByFollowing {
-    type Data = Project2;
+    type Data = ProjectMeta;
     type Error = api::Error;
     type This = Me;
     async fn gen(&self, cursor: Cursor, _this: &Arc<Self::This>, api: &Arc<Api>) -> GeneralStreamResult<Self> {
-        Ok(Project2::vec_new(api.projects_loved_by_following(cursor).await?, api.clone()))
+        Ok(ProjectMeta::vec_new(api.projects_loved_by_following(cursor).await?, api.clone()))
     }
 }
 // endregion: MeProjectsLovedByFollowing
@@ -18,11 +18,11 @@ use super::{stream::{GeneralStreamGen, GeneralStreamResult}, Project2, Me};
 // region: MeViewedProjects
 #[derive(Clone)] pub struct MeViewedProjects;
 #[async_trait] impl GeneralStreamGen for MeViewedProjects {
-    type Data = Project2;
+    type Data = ProjectMeta;
     type Error = api::Error;
     type This = Me;
     async fn gen(&self, cursor: Cursor, _this: &Arc<Self::This>, api: &Arc<Api>) -> GeneralStreamResult<Self> {
-        Ok(Project2::vec_new(api.viewed_projects(cursor).await?, api.clone()))
+        Ok(ProjectMeta::vec_new(api.viewed_projects(cursor).await?, api.clone()))
     }
 }
 // endregion: MeViewedProjects
@@ -30,11 +30,48 @@ use super::{stream::{GeneralStreamGen, GeneralStreamResult}, Project2, Me};
 // region: MeProjectsSharedByFollowing
 #[derive(Clone)] pub struct MeProjectsSharedByFollowing;
 #[async_trait] impl GeneralStreamGen for MeProjectsSharedByFollowing {
-    type Data = Project2;
+    type Data = ProjectMeta;
     type Error = api::Error;
     type This = Me;
     async fn gen(&self, cursor: Cursor, _this: &Arc<Self::This>, api: &Arc<Api>) -> GeneralStreamResult<Self> {
-        Ok(Project2::vec_new(api.projects_shared_by_following(cursor).await?, api.clone()))
+        Ok(ProjectMeta::vec_new(api.projects_shared_by_following(cursor).await?, api.clone()))
     }
 }
-// endregion: MeProjectsSharedByFollowing
\ No newline at end of file
+// endregion: MeProjectsSharedByFollowing
+
+// region: MeStuffProjects
+#[derive(Clone)] pub struct MeStuffProjects {
+    pub filter: api::StuffFilter,
+    pub sort: api::StuffSort,
+    pub direction: api::SortDirection,
+}
+#[async_trait] impl GeneralStreamGen for MeStuffProjects {
+    type Data = StuffProject;
+    type Error = api::Error;
+    type This = Me;
+    const LIMIT: usize = api::STUFF_PAGE_LIMIT;
+    const PAGED: bool = true;
+    async fn gen(&self, cursor: Cursor, _this: &Arc<Self::This>, api: &Arc<Api>) -> GeneralStreamResult<Self> {
+        let data = api.stuff_projects(self.filter, page_of(&cursor, Self::LIMIT), self.sort, self.direction).await?;
+        Ok(StuffProject::vec_new(trim_page(data, &cursor, Self::LIMIT), api.clone()))
+    }
+}
+// endregion: MeStuffProjects
+
+// region: MeStuffStudios
+#[derive(Clone)] pub struct MeStuffStudios {
+    pub sort: api::StuffSort,
+    pub direction: api::SortDirection,
+}
+#[async_trait] impl GeneralStreamGen for MeStuffStudios {
+    type Data = StuffStudio;
+    type Error = api::Error;
+    type This = Me;
+    const LIMIT: usize = api::STUFF_PAGE_LIMIT;
+    const PAGED: bool = true;
+    async fn gen(&self, cursor: Cursor, _this: &Arc<Self::This>, api: &Arc<Api>) -> GeneralStreamResult<Self> {
+        let data = api.stuff_studios(page_of(&cursor, Self::LIMIT), self.sort, self.direction).await?;
+        Ok(StuffStudio::vec_new(trim_page(data, &cursor, Self::LIMIT), api.clone()))
+    }
+}
+// endregion: MeStuffStudios
@@ -0,0 +1,114 @@
+use std::{sync::Arc, collections::VecDeque};
+use futures_util::Stream;
+use s2rs_derive::Forwarder;
+use crate::{api, Cursor};
+use async_trait::async_trait;
+use super::{Me, Message, watch::{AdaptiveInterval, SeenSet, Poller, watch_stream}};
+
+// region: MessageWatch
+#[derive(Debug, Clone, Default)]
+pub struct MessageWatchOptions {
+    pub interval: AdaptiveInterval,
+    /// Emit messages which were already unread when watching started
+    pub include_unread: bool,
+    /// Clear inbox after every batch of new messages
+    pub clear: bool,
+}
+
+#[derive(Forwarder, Debug)]
+pub enum WatchMessagesError {
+    #[forward] Count(api::GetUserMessagesCountError),
+    #[forward] Messages(api::GetUserMessagesError),
+    #[forward] Clear(api::Error),
+}
+
+struct MessageWatch {
+    me: Arc<Me>,
+    options: MessageWatchOptions,
+    count: Option<u64>,
+    seen: SeenSet<u64>,
+    pending: VecDeque<Arc<Message>>,
+}
+
+#[async_trait]
+impl Poller for MessageWatch {
+    type Item = Arc<Message>;
+    type Error = WatchMessagesError;
+
+    async fn poll(&mut self, _first: bool) -> Result<(), WatchMessagesError> {
+        let count = self.me.api.user_messages_count(&self.me.name).await?;
+        let last = *self.count.get_or_insert(if self.options.include_unread { 0 } else { count });
+        if count <= last {
+            self.count = Some(count);
+            if count < last {
+                self.options.interval.reset();
+            } else {
+                self.options.interval.back_off();
+            }
+            return Ok(())
+        }
+        self.options.interval.reset();
+
+        let mut messages = Vec::new();
+        for page in Cursor::new(0, Some((count - last) as usize)).pages(40, usize::MAX) {
+            messages.append(&mut self.me.api.user_messages(&self.me.name, page).await?);
+        }
+        // newest messages come first
+        for message in Message::vec_new(messages, self.me.api.clone()).into_iter().rev() {
+            if self.seen.insert(message.id) {
+                self.pending.push_back(message);
+            }
+        }
+
+        // count is only advanced once messages are fetched, so failed polls are retried
+        if self.options.clear {
+            self.me.api.clear_messages().await?;
+            self.count = Some(0);
+        } else {
+            self.count = Some(count);
+        }
+        Ok(())
+    }
+
+    fn pending(&mut self) -> Option<Result<Self::Item, Self::Error>> {
+        self.pending.pop_front().map(Ok)
+    }
+
+    fn interval(&mut self) -> &mut AdaptiveInterval {
+        &mut self.options.interval
+    }
+}
+// endregion: MessageWatch
+
+impl Me {
+    /// Watch inbox for new messages
+    /// - `Requires crate feature: 'watch'`
+    /// - Polls messages count, fetching messages only when it grows
+    /// - Errors are yielded without stopping the stream
+    /// # Examples
+    /// ```
+    /// # tokio_test::block_on(async {
+    /// # use s2rs::session::Session;
+    /// use futures_util::StreamExt;
+    /// use s2rs::entities::{MessageWatchOptions, MessageEvent};
+    /// # let session = Session::new("YourUsername");
+    /// let mut messages = Box::pin(session.me().watch_messages(MessageWatchOptions::default()));
+    /// while let Some(message) = messages.next().await {
+    ///     if let MessageEvent::Comment { fragment, .. } = &message.unwrap().event {
+    ///         dbg![fragment];
+    ///     }
+    /// }
+    /// # })
+    /// ```
+    pub fn watch_messages(self: &Arc<Self>, options: MessageWatchOptions) -> impl Stream<Item = Result<Arc<Message>, WatchMessagesError>> + Send {
+        let state = MessageWatch {
+            me: self.clone(),
+            options,
+            count: None,
+            seen: SeenSet::default(),
+            pending: VecDeque::new(),
+        };
+
+        watch_stream(state)
+    }
+}
@@ -1,12 +1,14 @@
 use super::{User, UserWithId, StudioWithTitle, ProjectWithTitle};
 use std::sync::Arc;
 use crate::api::{Api, self};
+use crate::date::Date;
 
 // region: Message
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Message {
     pub id: u64,
-    pub created_at: String,
+    pub created_at: Date,
     pub actor: Arc<UserWithId>,
     pub event: MessageEvent,
 }
@@ -28,7 +30,8 @@ impl Message {
 // endregion: Message
 
 // region: MessageCommentLocation
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum MessageCommentLocation {
     Profile(Arc<UserWithId>),
     Studio(Arc<StudioWithTitle>),
@@ -53,7 +56,8 @@ impl MessageCommentLocation {
 // endregion: MessageCommentLocation
 
 // region: MessageEvent
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum MessageEvent {
     FollowUser(Arc<UserWithId>),
     LoveProject(Arc<ProjectWithTitle>),
@@ -75,7 +79,12 @@ pub enum MessageEvent {
         id: u64,
         title: String,
     },
-    Welcome
+    PromoteHostStudio(Arc<StudioWithTitle>),
+    Welcome,
+    Unknown {
+        event_type: String,
+        raw: serde_json::Value,
+    }
 }
 
 impl MessageEvent {
@@ -122,7 +131,13 @@ impl MessageEvent {
                 StudioWithTitle::new(title, id, api)
             ),
 
-            api::MessageEvent::Welcome => Self::Welcome
+            api::MessageEvent::PromoteHostStudio { id, title } => Self::PromoteHostStudio(
+                StudioWithTitle::new(title, id, api)
+            ),
+
+            api::MessageEvent::Welcome => Self::Welcome,
+
+            api::MessageEvent::Unknown { event_type, raw } => Self::Unknown { event_type, raw }
         }
     }
 }
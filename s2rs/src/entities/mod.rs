@@ -1,5 +1,5 @@
 use crate::api::Api;
-#[cfg(feature = "stream")] pub use stream::Stream;
+#[cfg(feature = "stream")] pub use stream::{Stream, StreamCheckpoint, FileCheckpoint, SkippedEntries};
 pub use user::*;
 pub use project::*;
 pub use studio::*;
@@ -18,6 +18,7 @@ pub use forum::*;
 pub use login::*;
 pub use user_project::*;
 pub use project_comment::*;
+pub use stuff::*;
 
 pub mod user;
 pub mod project;
@@ -37,18 +38,31 @@ pub mod forum;
 pub mod login;
 pub mod user_project;
 pub mod project_comment;
+pub mod stuff;
 
 #[cfg(feature = "stream")] pub mod stream;
 #[cfg(feature = "stream")] pub mod user_stream;
 #[cfg(feature = "stream")] pub mod project_stream;
 #[cfg(feature = "stream")] pub mod studio_stream;
 #[cfg(feature = "stream")] pub mod search;
-#[cfg(feature = "stream")] pub mod explore;
 #[cfg(feature = "stream")] pub mod me_stream;
+#[cfg(all(feature = "stream", feature = "html"))] pub mod forum_stream;
 
 #[cfg(feature = "stream")] pub use user_stream::*;
 #[cfg(feature = "stream")] pub use project_stream::*;
 #[cfg(feature = "stream")] pub use studio_stream::*;
 #[cfg(feature = "stream")] pub use search::*;
-#[cfg(feature = "stream")] pub use explore::*;
-#[cfg(feature = "stream")] pub use me_stream::*;
\ No newline at end of file
+#[cfg(feature = "stream")] pub use me_stream::*;
+#[cfg(all(feature = "stream", feature = "html"))] pub use forum_stream::*;
+
+#[cfg(feature = "watch")] pub mod watch;
+#[cfg(feature = "watch")] pub mod me_watch;
+#[cfg(feature = "watch")] pub mod studio_watch;
+#[cfg(feature = "watch")] pub mod project_watch;
+#[cfg(all(feature = "watch", feature = "rss"))] pub mod forum_watch;
+
+#[cfg(feature = "watch")] pub use watch::{AdaptiveInterval, SeenSet};
+#[cfg(feature = "watch")] pub use me_watch::*;
+#[cfg(feature = "watch")] pub use studio_watch::StudioActivityWatchOptions;
+#[cfg(feature = "watch")] pub use project_watch::CloudActivityWatchOptions;
+#[cfg(all(feature = "watch", feature = "rss"))] pub use forum_watch::{ForumWatchOptions, ForumWatchPost, ForumWatchError, ForumWatchCheckpoint, ForumWatchFile, ForumSeenPosts};
@@ -3,8 +3,10 @@
 use std::sync::Arc;
 use derivative::Derivative;
 use crate::api::{Api, self, SendComment};
-#[cfg(feature = "stream")] use crate::cursor::Cursor;
-use super::{User, ProjectComment};
+use crate::cursor::Cursor;
+use crate::utils::meta_cache::MetaCache;
+use super::{User, ProjectComment, CloudAction, CloudExportFormat, ExportCloudActivityError};
+#[cfg(feature = "watch")] use super::{project_watch, CloudActivityWatchOptions};
 #[cfg(feature = "web_socket")] use super::Cloud;
 #[cfg(feature = "stream")] use super::{project_stream::*, stream::GeneralStream};
 use s2rs_derive::deref;
@@ -21,8 +23,9 @@ use s2rs_derive::deref;
 /// dbg![meta.title, meta.id];
 /// # })
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 #[deref(this)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ProjectWithTitle {
     pub title: String,
     pub this: Arc<Project>
@@ -43,7 +46,8 @@ impl ProjectWithTitle {
 // endregion: ProjectWithTitle
 
 // region: ProjectCore
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ProjectCoreRaw {
     pub description: String,
     pub instructions: String,
@@ -58,8 +62,9 @@ pub struct ProjectCoreRaw {
     pub history: api::ProjectHistory,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[deref(this)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ProjectCore {
     pub this: Arc<ProjectWithTitle>,
     pub description: String,
@@ -101,7 +106,8 @@ impl ProjectCore {
 
 // region: ProjectMeta
 /// Project metadata
-/// - Mapping for <https://api.scratch.mit.edu/projects/PROJECT-ID>
+/// - Mapping for <https://api.scratch.mit.edu/projects/PROJECT-ID> and every project listing
+/// - `token` is only present when fetched by [`Project::meta`] of a handle not coming from a listing
 /// # Examples
 /// ```
 /// # tokio_test::block_on(async {
@@ -116,17 +122,20 @@ impl ProjectCore {
 /// ];
 /// # })
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[deref(this)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ProjectMeta {
     pub this: Arc<ProjectCore>,
     pub author: ProjectAuthor,
-    pub token: String
+    pub token: Option<String>
 }
 
 impl ProjectMeta {
-    pub fn with_this_this(data: api::Project, this: Arc<ProjectWithTitle>, api: Arc<Api>) -> Arc<Self> {
+    fn with_author(data: api::Project, this: Arc<ProjectWithTitle>, author: impl FnOnce(api::ProjectAuthor) -> ProjectAuthor) -> Arc<Self> {
         Arc::new(Self {
+            author: author(data.author),
+            token: data.token,
             this: ProjectCore::with_this(
                 ProjectCoreRaw {
                     comments_allowed: data.comments_allowed,
@@ -142,25 +151,36 @@ impl ProjectMeta {
                     visibility: data.visibility
                 },
             this),
-            author: ProjectAuthor::new(data.author, api),
-            token: data.token
         })
     }
 
+    pub fn with_this_this(data: api::Project, this: Arc<ProjectWithTitle>, api: Arc<Api>) -> Arc<Self> {
+        Self::with_author(data, this, |author| ProjectAuthor::new(author, api))
+    }
+
     pub fn with_this_this_this(data: api::Project, this: Arc<Project>, api: Arc<Api>) -> Arc<Self> {
         let title = data.title.clone();
         Self::with_this_this(data, ProjectWithTitle::with_this(title, this), api)
     }
 
+    /// Prefills the created [`Project`] handle, so its [`Project::meta`] won't hit the network
     pub fn new(data: api::Project, api: Arc<Api>) -> Arc<Self> {
-        let id = data.id;
-        let title = data.title.clone();
-        Self::with_this_this(data, ProjectWithTitle::new(title, id, api.clone()), api)
+        let this = Project::with_meta(data.clone(), api.clone());
+        Self::with_this_this_this(data, this, api)
     }
 
     pub fn vec_new(data: Vec<api::Project>, api: Arc<Api>) -> Vec<Arc<Self>> {
         data.into_iter().map(|data| Self::new(data, api.clone())).collect()
     }
+
+    /// Same as [`ProjectMeta::vec_new`], but fills in `author` for listings which don't include author's name
+    pub fn vec_with_author(data: Vec<api::Project>, author: Arc<User>, api: Arc<Api>) -> Vec<Arc<Self>> {
+        data.into_iter().map(|mut data| {
+            data.author.name.get_or_insert_with(|| author.name.to_string());
+            let this = ProjectWithTitle::with_this(data.title.clone(), Project::with_meta(data.clone(), api.clone()));
+            Self::with_author(data, this, |data| ProjectAuthor::with_this(data, author.clone()))
+        }).collect()
+    }
 }
 // endregion: ProjectMeta
 
@@ -176,10 +196,12 @@ impl ProjectMeta {
 /// dbg![&author.scratch_team];
 /// # })
 /// ```
-#[derive(Debug)]
-#[deref(this)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ProjectAuthor {
-    pub this: Arc<User>,
+    pub id: u64,
+    /// `None` when listing doesn't include author's name
+    pub user: Option<Arc<User>>,
     pub scratch_team: bool,
     pub history: api::UserHistory,
     pub profile: api::ProjectAuthorProfile,
@@ -188,7 +210,8 @@ pub struct ProjectAuthor {
 impl ProjectAuthor {
     pub fn new(data: api::ProjectAuthor, api: Arc<Api>) -> Self {
         Self {
-            this: User::new(data.name, api),
+            user: data.name.map(|name| User::new(name, api)),
+            id: data.id,
             history: data.history,
             profile: data.profile,
             scratch_team: data.scratch_team,
@@ -197,7 +220,8 @@ impl ProjectAuthor {
 
     pub fn with_this(data: api::ProjectAuthor, this: Arc<User>) -> Self {
         Self {
-            this,
+            user: Some(this),
+            id: data.id,
             history: data.history,
             profile: data.profile,
             scratch_team: data.scratch_team,
@@ -206,112 +230,6 @@ impl ProjectAuthor {
 }
 // endregion: ProjectAuthor
 
-// region: Project3
-/// Partial project
-/// - Used to map some parts of API that return project metadata which is not the same as [`ProjectMeta`]
-/// # Examples TODO
-/// ```
-/// # tokio_test::block_on(async {
-/// # use s2rs::session::Session;
-/// # let session = Session::new("YourUsername");
-/// let project = session.project(823872487);
-/// let author = &project.meta().await.unwrap().author;
-/// dbg![&author.scratch_team];
-/// # })
-/// ```
-#[derive(Debug)]
-#[deref(this)]
-pub struct Project3 {
-    pub this: Arc<ProjectCore>,
-    pub author: api::Project3Author,
-}
-
-impl Project3 {
-    pub fn with_this_this(data: api::Project3, this: Arc<ProjectWithTitle>) -> Arc<Self> {
-        Arc::new(Self {
-            author: data.author,
-            this: ProjectCore::with_this(
-                ProjectCoreRaw {
-                    comments_allowed: data.comments_allowed,
-                    description: data.description,
-                    history: data.history,
-                    image: data.image,
-                    images: data.images,
-                    instructions: data.instructions,
-                    is_published: data.is_published,
-                    public: data.public,
-                    remix: data.remix,
-                    stats: data.stats,
-                    visibility: data.visibility
-                },
-            this),
-        })
-    }
-
-    pub fn with_this_this_this(data: api::Project3, this: Arc<Project>) -> Arc<Self> {
-        let title = data.title.clone();
-        Self::with_this_this(data, ProjectWithTitle::with_this(title, this))
-    }
-
-    pub fn new(data: api::Project3, api: Arc<Api>) -> Arc<Self> {
-        let id = data.id;
-        let title = data.title.clone();
-        Self::with_this_this(data, ProjectWithTitle::new(title, id, api))
-    }
-
-    pub fn vec_new(data: Vec<api::Project3>, api: Arc<Api>) -> Vec<Arc<Self>> {
-        data.into_iter().map(|data| Self::new(data, api.clone())).collect()
-    }
-}
-// endregion: Project3
-
-// region: Project2
-#[deref(this)]
-#[derive(Debug)]
-pub struct Project2 {
-    pub this: Arc<ProjectCore>,
-    pub author: ProjectAuthor,
-}
-
-impl Project2 {
-    pub fn with_this_this(data: api::Project2, this: Arc<ProjectWithTitle>, api: Arc<Api>) -> Arc<Self> {
-        Arc::new(Self {
-            author: ProjectAuthor::new(data.author, api),
-            this: ProjectCore::with_this(
-                ProjectCoreRaw {
-                    comments_allowed: data.comments_allowed,
-                    description: data.description,
-                    history: data.history,
-                    image: data.image,
-                    images: data.images,
-                    instructions: data.instructions,
-                    is_published: data.is_published,
-                    public: data.public,
-                    remix: data.remix,
-                    stats: data.stats,
-                    visibility: data.visibility
-                },
-            this),
-        })
-    }
-
-    pub fn with_this_this_this(data: api::Project2, this: Arc<Project>, api: Arc<Api>) -> Arc<Self> {
-        let title = data.title.clone();
-        Self::with_this_this(data, ProjectWithTitle::with_this(title, this), api)
-    }
-
-    pub fn new(data: api::Project2, api: Arc<Api>) -> Arc<Self> {
-        let id = data.id;
-        let title = data.title.clone();
-        Self::with_this_this(data, ProjectWithTitle::new(title, id, api.clone()), api)
-    }
-
-    pub fn vec_new(data: Vec<api::Project2>, api: Arc<Api>) -> Vec<Arc<Self>> {
-        data.into_iter().map(|data| Self::new(data, api.clone())).collect()
-    }
-}
-// endregion: Project2
-
 // region: Project
 
 /// Project identifier
@@ -340,11 +258,16 @@ impl Project2 {
 /// // ...
 /// # })
 /// ```
-#[derive(Derivative)]
+#[derive(Derivative, Clone)]
 #[derivative(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Project {
     #[derivative(Debug="ignore", PartialEq="ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     api: Arc<Api>,
+    #[derivative(Debug="ignore", PartialEq="ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    meta: MetaCache<api::Project>,
     pub id: u64,
 }
 
@@ -352,14 +275,47 @@ impl Project {
     pub fn new(id: u64, api: Arc<Api>) -> Arc<Self> {
         Arc::new(Self {
             api,
+            meta: MetaCache::default(),
             id
         })
     }
+
+    fn with_meta(data: api::Project, api: Arc<Api>) -> Arc<Self> {
+        Arc::new(Self {
+            api,
+            id: data.id,
+            meta: MetaCache::new(Some(data)),
+        })
+    }
 }
 
 impl Project {
+    /// Project's metadata
+    /// - Fetched on the first call and cached in this handle, use [`Project::refresh`] to fetch it again
+    /// - Handles coming from listings are prefilled, their `token` is `None` until [`Project::refresh`]
     pub async fn meta(self: &Arc<Self>) -> Result<Arc<ProjectMeta>, api::Error> {
-        Ok(ProjectMeta::with_this_this_this(self.api.project_meta(self.id).await?, self.clone(), self.api.clone()))
+        let data = match self.meta.get() {
+            Some(data) => data,
+            None => {
+                let data = self.api.project_meta(self.id).await?;
+                self.meta.set(data.clone());
+                data
+            }
+        };
+        Ok(ProjectMeta::with_this_this_this(data, self.clone(), self.api.clone()))
+    }
+
+    /// Same as [`Project::meta`], but always fetches fresh metadata and replaces the cached one
+    pub async fn refresh(self: &Arc<Self>) -> Result<Arc<ProjectMeta>, api::Error> {
+        let data = self.api.refresh_project_meta(self.id).await?;
+        self.meta.set(data.clone());
+        Ok(ProjectMeta::with_this_this_this(data, self.clone(), self.api.clone()))
+    }
+
+    /// Removes cached metadata, next [`Project::meta`] call will hit the network
+    pub fn invalidate_meta(&self) {
+        self.meta.clear();
+        self.api.invalidate_project_meta(self.id)
     }
 
     pub fn comment(self: &Arc<Self>, id: u64) -> Arc<ProjectComment> {
@@ -376,6 +332,69 @@ impl Project {
         GeneralStream::with_this(ProjectCloudActivity, cursor.into(), self.clone(), self.api.clone())
     }
 
+    /// Watch cloud log for new actions
+    /// - `Requires crate feature: 'watch'`
+    /// - Useful when cloud web socket isn't available
+    /// - Errors are yielded without stopping the stream
+    /// # Examples
+    /// ```
+    /// # tokio_test::block_on(async {
+    /// # use s2rs::session::Session;
+    /// use futures_util::StreamExt;
+    /// use s2rs::entities::CloudActivityWatchOptions;
+    /// # let session = Session::new("YourUsername");
+    /// let project = session.project(823872487);
+    /// let mut actions = Box::pin(project.watch_cloud_activity(CloudActivityWatchOptions::default()));
+    /// while let Some(action) = actions.next().await {
+    ///     dbg![action.unwrap()];
+    /// }
+    /// # })
+    /// ```
+    #[cfg(feature = "watch")]
+    pub fn watch_cloud_activity(self: &Arc<Self>, options: CloudActivityWatchOptions) -> impl futures_util::Stream<Item = Result<Arc<CloudAction>, api::GetProjectCloudActivityError>> + Send {
+        project_watch::watch_activity(self.clone(), self.api.clone(), options)
+    }
+
+    /// Write whole cloud log to `writer`, oldest actions first
+    /// - Returns number of written actions
+    /// # Examples
+    /// ```
+    /// # tokio_test::block_on(async {
+    /// # use s2rs::session::Session;
+    /// use s2rs::entities::CloudExportFormat;
+    /// # let session = Session::new("YourUsername");
+    /// let project = session.project(823872487);
+    /// let mut file = std::fs::File::create("cloud.csv").unwrap();
+    /// project.export_cloud_activity(&mut file, CloudExportFormat::Csv).await.unwrap();
+    /// # })
+    /// ```
+    pub async fn export_cloud_activity(&self, writer: &mut (impl std::io::Write + Send), format: CloudExportFormat) -> Result<usize, ExportCloudActivityError> {
+        let mut actions = Vec::new();
+        let mut cursor = Cursor::new(0, None);
+        loop {
+            let page = cursor.progress(100);
+            let mut data = self.api.project_cloud_activity(self.id, page).await?;
+            let done = data.len() < 100;
+            actions.append(&mut data);
+            if done {
+                break
+            }
+        }
+
+        let count = actions.len();
+        if format == CloudExportFormat::Csv {
+            writeln!(writer, "{}", CloudAction::CSV_HEADER)?;
+        }
+        for action in CloudAction::vec_new(actions, self.api.clone()).iter().rev() {
+            match format {
+                CloudExportFormat::Csv => writeln!(writer, "{}", action.to_csv())?,
+                CloudExportFormat::JsonLines => writeln!(writer, "{}", action.to_json())?,
+            }
+        }
+        writer.flush()?;
+        Ok(count)
+    }
+
     #[cfg(feature = "web_socket")]
     pub async fn cloud(self: &Arc<Self>, id: u64) -> Result<Arc<Cloud>, tokio_tungstenite::tungstenite::Error> {
         Ok(Cloud::new(5, self.api.project_cloud(id).await?))
@@ -401,6 +420,64 @@ impl Project {
         self.api.unshare_project(self.id).await
     }
 
+    pub async fn share(&self) -> Result<(), api::Error> {
+        self.api.share_project(self.id).await
+    }
+
+    /// Moves project to trash, it can be restored with [`Project::restore`]
+    pub async fn trash(&self) -> Result<(), api::Error> {
+        self.api.trash_project(self.id).await
+    }
+
+    /// Moves project out of trash
+    pub async fn restore(&self) -> Result<(), api::Error> {
+        self.api.restore_project(self.id).await
+    }
+
+    /// Permanently deletes project, it has to be in trash first
+    pub async fn delete(&self) -> Result<(), api::Error> {
+        self.api.delete_project(self.id).await
+    }
+
+    /// Project's `project.json`
+    pub async fn json(&self) -> Result<serde_json::Value, api::Error> {
+        let token = self.api.refresh_project_meta(self.id).await?.token;
+        self.api.project_json(self.id, token.as_deref()).await
+    }
+
+    async fn create_from(&self, title: Option<String>, remix: bool) -> Result<Arc<Project>, api::CreateProjectError> {
+        let meta = self.api.refresh_project_meta(self.id).await?;
+        let data = self.api.project_json(self.id, meta.token.as_deref()).await?;
+        let title = title.unwrap_or_else(|| format!["{} {}", meta.title, if remix { "remix" } else { "copy" }]);
+        let id = if remix {
+            self.api.remix_project(self.id, &title, &data).await?
+        } else {
+            self.api.copy_project(self.id, &title, &data).await?
+        };
+        Ok(Project::new(id, self.api.clone()))
+    }
+
+    /// Remixes project as logged in user
+    /// - `title` defaults to the same title Scratch uses, which is "`TITLE` remix"
+    /// # Examples
+    /// ```
+    /// # tokio_test::block_on(async {
+    /// # use s2rs::session::Session;
+    /// # let session = Session::new("YourUsername");
+    /// let template = session.project(823872487);
+    /// let remix = template.remix(Some("Student project".to_owned())).await.unwrap();
+    /// dbg![remix.id];
+    /// # })
+    /// ```
+    pub async fn remix(&self, title: Option<String>) -> Result<Arc<Project>, api::CreateProjectError> {
+        self.create_from(title, true).await
+    }
+
+    /// Same as [`Project::remix`], but the new project isn't marked as remix and `title` defaults to "`TITLE` copy"
+    pub async fn save_as_copy(&self, title: Option<String>) -> Result<Arc<Project>, api::CreateProjectError> {
+        self.create_from(title, false).await
+    }
+
     pub async fn send_comment(&self, data: impl Into<SendComment>) -> Result<(), api::Error> {
         self.api.send_project_comment(self.id, &data.into()).await
     }
@@ -4,12 +4,14 @@ use crate::{Api, api::{SendComment, self}};
 use super::Project;
 
 #[allow(unused)]
-#[derive(Derivative)]
+#[derive(Derivative, Clone)]
 #[derivative(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ProjectComment {
     pub id: u64,
     pub at: Arc<Project>,
     #[derivative(Debug = "ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     api: Arc<Api>,
 }
 
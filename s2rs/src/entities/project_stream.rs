@@ -1,17 +1,17 @@
 use std::sync::Arc;
 use crate::api::{self, Api};
 use crate::cursor::Cursor;
-use super::{stream::{GeneralStreamGen, GeneralStreamResult}, Project, Project3, CloudAction};
+use super::{stream::{GeneralStreamGen, GeneralStreamResult}, Project, ProjectMeta, CloudAction};
 use async_trait::async_trait;
 
 // region: ProjectRemixes
 #[derive(Clone)] pub struct ProjectRemixes;
 #[async_trait] impl GeneralStreamGen for ProjectRemixes {
-    type Data = Project3;
+    type Data = ProjectMeta;
     type Error = api::Error;
     type This = Project;
     async fn gen(&self, cursor: Cursor, this: &Arc<Self::This>, api: &Arc<Api>) -> GeneralStreamResult<Self> {
-        Ok(Project3::vec_new(api.project_remixes(this.id, cursor).await?, api.clone()))
+        Ok(ProjectMeta::vec_new(api.project_remixes(this.id, cursor).await?, api.clone()))
     }
 }
 // endregion: ProjectRemixes
@@ -22,6 +22,7 @@ use async_trait::async_trait;
     type Data = CloudAction;
     type Error = api::GetProjectCloudActivityError;
     type This = Project;
+    const LIMIT: usize = 100;
     async fn gen(&self, cursor: Cursor, this: &Arc<Self::This>, api: &Arc<Api>) -> GeneralStreamResult<Self> {
         Ok(CloudAction::vec_new(api.project_cloud_activity(this.id, cursor).await?, api.clone()))
     }
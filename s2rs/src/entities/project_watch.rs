@@ -0,0 +1,110 @@
+use std::{sync::Arc, collections::{HashSet, VecDeque}};
+use futures_util::Stream;
+use crate::{api::{self, Api}, Cursor};
+use async_trait::async_trait;
+use super::{Project, CloudAction, watch::{AdaptiveInterval, Poller, watch_stream}};
+
+// region: CloudActivityWatch
+#[derive(Debug, Clone)]
+pub struct CloudActivityWatchOptions {
+    pub interval: AdaptiveInterval,
+    /// Timestamp of the last handled action, actions after it are emitted on the first poll
+    /// - When `None` only actions made after watching started are emitted
+    pub since: Option<u64>,
+    /// Limit of pages fetched at once when lots of actions happened between polls
+    pub max_pages: usize,
+}
+
+impl Default for CloudActivityWatchOptions {
+    fn default() -> Self {
+        Self {
+            interval: AdaptiveInterval::default(),
+            since: None,
+            max_pages: 5
+        }
+    }
+}
+
+struct CloudActivityWatch {
+    project: Arc<Project>,
+    api: Arc<Api>,
+    options: CloudActivityWatchOptions,
+    /// Actions sharing the `since` timestamp which were already emitted
+    at_since: HashSet<String>,
+    pending: VecDeque<Arc<CloudAction>>,
+}
+
+fn action_key(action: &api::CloudAction) -> String {
+    format!["{}|{:?}", action.by_name, action.event]
+}
+
+#[async_trait]
+impl Poller for CloudActivityWatch {
+    type Item = Arc<CloudAction>;
+    type Error = api::GetProjectCloudActivityError;
+
+    async fn poll(&mut self, first: bool) -> Result<(), api::GetProjectCloudActivityError> {
+        let since = self.options.since;
+        let mut actions = Vec::new();
+        // log comes newest first
+        'pages: for page in Cursor::new(0, None).pages(100, self.options.max_pages.max(1)) {
+            let data = self.api.project_cloud_activity(self.project.id, page).await?;
+            let full = data.len() == 100;
+            for action in data {
+                if since.is_some_and(|since| action.timestamp < since) {
+                    break 'pages
+                }
+                if since == Some(action.timestamp) {
+                    // actions at a resumed `since` were handled before watching started
+                    if first {
+                        self.at_since.insert(action_key(&action));
+                    }
+                    if self.at_since.contains(&action_key(&action)) {
+                        continue
+                    }
+                }
+                actions.push(action);
+            }
+            if !full || since.is_none() {
+                break
+            }
+        }
+
+        let Some(newest) = actions.first().map(|action| action.timestamp) else {
+            self.options.interval.back_off();
+            return Ok(())
+        };
+        if Some(newest) != since {
+            self.at_since.clear();
+        }
+        self.at_since.extend(actions.iter().filter(|action| action.timestamp == newest).map(action_key));
+        self.options.since = Some(newest);
+        self.options.interval.reset();
+
+        if since.is_some() {
+            self.pending.extend(CloudAction::vec_new(actions, self.api.clone()).into_iter().rev());
+        }
+        Ok(())
+    }
+
+    fn pending(&mut self) -> Option<Result<Self::Item, Self::Error>> {
+        self.pending.pop_front().map(Ok)
+    }
+
+    fn interval(&mut self) -> &mut AdaptiveInterval {
+        &mut self.options.interval
+    }
+}
+
+pub(super) fn watch_activity(project: Arc<Project>, api: Arc<Api>, options: CloudActivityWatchOptions) -> impl Stream<Item = Result<Arc<CloudAction>, api::GetProjectCloudActivityError>> + Send {
+    let state = CloudActivityWatch {
+        project,
+        api,
+        options,
+        at_since: HashSet::new(),
+        pending: VecDeque::new(),
+    };
+
+    watch_stream(state)
+}
+// endregion: CloudActivityWatch
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use crate::api::{self, SearchQuery};
 use crate::{Api, cursor::Cursor};
-use super::{Project2, Me, Studio2};
+use super::{ProjectMeta, Me, StudioMeta};
 use super::stream::{GeneralStreamResult, GeneralStreamGen, GeneralStream};
 use std::sync::Arc;
 
@@ -10,11 +10,11 @@ use std::sync::Arc;
     pub query: SearchQuery
 }
 #[async_trait] impl GeneralStreamGen for SearchProjects {
-    type Data = Project2;
+    type Data = ProjectMeta;
     type Error = api::Error;
     type This = Me;
     async fn gen(&self, cursor: Cursor, _: &Arc<Self::This>, api: &Arc<Api>) -> GeneralStreamResult<Self> {
-        Ok(Project2::vec_new(api.search_projects(&self.query, cursor).await?, api.clone()))
+        Ok(ProjectMeta::vec_new(api.search_projects(&self.query, cursor).await?, api.clone()))
     }
 }
 
@@ -22,20 +22,38 @@ use std::sync::Arc;
     pub query: SearchQuery
 }
 #[async_trait] impl GeneralStreamGen for SearchStudios {
-    type Data = Studio2;
+    type Data = StudioMeta;
     type Error = api::Error;
     type This = Me;
     async fn gen(&self, cursor: Cursor, _: &Arc<Self::This>, api: &Arc<Api>) -> GeneralStreamResult<Self> {
-        Ok(Studio2::vec_new(api.search_studios(&self.query, cursor).await?, api.clone()))
+        Ok(StudioMeta::vec_new(api.search_studios(&self.query, cursor).await?, api.clone()))
     }
 }
 // endregion: streams
 
 impl Me {
+    /// Projects matching `query`, paged through until there are no more hits
+    /// # Examples
+    /// ```
+    /// # tokio_test::block_on(async {
+    /// # use s2rs::session::Session;
+    /// use s2rs::{api::{SearchQuery, SearchMode}, entities::Stream, Language};
+    /// # let session = Session::new("YourUsername");
+    /// # let me = session.me();
+    /// let query = SearchQuery::new("platformer").with_mode(SearchMode::Recent).with_language(Language::English);
+    /// let mut results = me.search_projects(query, (0, 200));
+    /// while let Some(page) = results.next().await {
+    ///     for project in page.unwrap() {
+    ///         dbg![ &project.title ];
+    ///     }
+    /// }
+    /// # })
+    /// ```
     pub fn search_projects(self: &Arc<Self>, query: SearchQuery, cursor: impl Into<Cursor>) -> GeneralStream<SearchProjects> {
         GeneralStream::with_this(SearchProjects { query }, cursor.into(), self.clone(), self.api.clone())
     }
 
+    /// Studios matching `query`, paged through until there are no more hits
     pub fn search_studios(self: &Arc<Self>, query: SearchQuery, cursor: impl Into<Cursor>) -> GeneralStream<SearchStudios> {
         GeneralStream::with_this(SearchStudios { query }, cursor.into(), self.clone(), self.api.clone())
     }
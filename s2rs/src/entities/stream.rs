@@ -1,5 +1,6 @@
-use std::sync::Arc;
+use std::{sync::{Arc, Mutex}, path::PathBuf};
 use async_trait::async_trait;
+use futures_util::future::try_join_all;
 use crate::api::Api;
 use crate::cursor::Cursor;
 
@@ -38,6 +39,107 @@ pub trait Stream where Self: Sized + Send + Sync {
     }
 }
 
+// region: StreamCheckpoint
+/// Receives stream [`Cursor`] every time a page is fetched, so a crawl can be resumed later
+/// - Pass the saved cursor to the same stream method (e.g. `user.followers(cursor)`) to continue where it stopped
+pub trait StreamCheckpoint: Send + Sync {
+    fn save(&self, cursor: &Cursor);
+}
+
+/// Persists stream checkpoints as JSON file
+/// # Examples
+/// ```
+/// # tokio_test::block_on(async {
+/// # use s2rs::session::Session;
+/// use std::sync::Arc;
+/// use s2rs::entities::{Stream, FileCheckpoint};
+/// # let session = Session::new("YourUsername");
+/// let checkpoint = Arc::new(FileCheckpoint::new("followers.json"));
+/// let cursor = checkpoint.load().unwrap_or_default();
+/// let mut followers = session.user("griffpatch").followers(cursor).with_checkpoint(checkpoint);
+/// while let Some(page) = followers.next().await {
+///     dbg![page.unwrap().len()];
+/// }
+/// # })
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileCheckpoint {
+    pub path: PathBuf,
+}
+
+impl FileCheckpoint {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into()
+        }
+    }
+
+    /// Last saved cursor, `None` if there is no checkpoint yet or it can't be read
+    pub fn load(&self) -> Option<Cursor> {
+        serde_json::from_slice(&std::fs::read(&self.path).ok()?).ok()
+    }
+}
+
+impl StreamCheckpoint for FileCheckpoint {
+    /// - Write failures are ignored, stream keeps going and the previous checkpoint stays on disk
+    fn save(&self, cursor: &Cursor) {
+        if let Ok(data) = serde_json::to_vec(cursor) {
+            let _ = std::fs::write(&self.path, data);
+        }
+    }
+}
+// endregion: StreamCheckpoint
+
+// region: pages
+/// Page number of endpoints which take `page` starting at 1 instead of offset
+pub(super) fn page_of(cursor: &Cursor, limit: usize) -> u32 {
+    u32::try_from(cursor.start / limit + 1).unwrap_or(u32::MAX)
+}
+
+/// Paged endpoints can't start at any offset, so the page containing `cursor.start` is fetched and trimmed to the cursor
+/// - Entries which failed to parse have to be kept, so page offsets stay in sync
+/// - Generators using it have to set [`GeneralStreamGen::PAGED`], so pages fetched in parallel don't cross page boundaries
+pub(super) fn trim_page<T>(data: Vec<T>, cursor: &Cursor, limit: usize) -> Vec<T> {
+    data.into_iter()
+    .skip(cursor.start % limit)
+    .take(cursor.get_limit().unwrap_or(limit))
+    .collect()
+}
+// endregion: pages
+
+// region: SkippedEntries
+/// Entries of a stream which couldn't be parsed, they are left out of stream data
+/// - Shared between clones, so the stream can keep filling it
+#[derive(Debug)]
+pub struct SkippedEntries<E>(Arc<Mutex<Vec<E>>>);
+
+impl<E> SkippedEntries<E> {
+    /// Takes entries skipped so far
+    pub fn take(&self) -> Vec<E> {
+        std::mem::take(&mut *self.0.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// Keeps parsed entries, remembering the rest
+    #[cfg(feature = "html")]
+    pub(super) fn split<T>(&self, entries: Vec<Result<Arc<T>, E>>) -> Vec<Arc<T>> {
+        let mut skipped = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        entries.into_iter().filter_map(|entry| entry.map_err(|error| skipped.push(error)).ok()).collect()
+    }
+}
+
+impl<E> Default for SkippedEntries<E> {
+    fn default() -> Self {
+        Self(Arc::default())
+    }
+}
+
+impl<E> Clone for SkippedEntries<E> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+// endregion: SkippedEntries
+
 pub type GeneralStreamResult<S> = Result<Vec<Arc<<S as GeneralStreamGen>::Data>>, <S as GeneralStreamGen>::Error>;
 
 // region: GeneralStream
@@ -46,7 +148,19 @@ pub trait GeneralStreamGen {
     type Data: Send + Sync;
     type Error: Send + Sync;
     type This: Send + Sync;
+    /// Maximum amount of items the endpoint returns per request
+    const LIMIT: usize = 40;
+    /// Endpoint takes page numbers instead of offsets, so parallel requests have to start at page boundaries, see [`trim_page`]
+    const PAGED: bool = false;
     async fn gen(&self, cursor: Cursor, this: &Arc<Self::This>, api: &Arc<Api>) -> GeneralStreamResult<Self>;
+
+    /// Same as [`GeneralStreamGen::gen`], along with the amount of entries the endpoint returned
+    /// - Has to be implemented when entries get dropped, e.g. ones which failed to parse, so the cursor doesn't fall behind
+    async fn gen_counted(&self, cursor: Cursor, this: &Arc<Self::This>, api: &Arc<Api>) -> Result<(Vec<Arc<Self::Data>>, usize), Self::Error> {
+        let data = self.gen(cursor, this, api).await?;
+        let len = data.len();
+        Ok((data, len))
+    }
 }
 
 pub struct GeneralStream<G: GeneralStreamGen + Clone + Send + Sync> {
@@ -54,6 +168,7 @@ pub struct GeneralStream<G: GeneralStreamGen + Clone + Send + Sync> {
     this: Arc<G::This>,
     cursor: Cursor,
     gen: G,
+    checkpoint: Option<Arc<dyn StreamCheckpoint>>,
 }
 
 impl<G: GeneralStreamGen + Clone + Send + Sync> GeneralStream<G> {
@@ -62,9 +177,72 @@ impl<G: GeneralStreamGen + Clone + Send + Sync> GeneralStream<G> {
             api,
             cursor,
             gen,
-            this
+            this,
+            checkpoint: None,
         }
     }
+
+    /// Saves stream cursor to `checkpoint` after each fetched page
+    pub fn with_checkpoint(mut self, checkpoint: Arc<dyn StreamCheckpoint>) -> Self {
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// Generator of the stream, e.g. to get its [`SkippedEntries`]
+    pub fn gen(&self) -> &G {
+        &self.gen
+    }
+
+    /// Current position of the stream, can be serialized and passed back to resume it
+    pub fn cursor(&self) -> &Cursor {
+        &self.cursor
+    }
+
+    fn save_checkpoint(&self) {
+        if let Some(checkpoint) = &self.checkpoint {
+            checkpoint.save(&self.cursor);
+        }
+    }
+
+    /// Fetches next `concurrency` pages at the same time
+    /// - Results are returned in the same order as [`Stream::progress`] would return them
+    /// - If cursor has no end, stream stops after the first page that is not full
+    pub async fn progress_parallel(&mut self, concurrency: usize) -> StreamResult<Self> {
+        let pages = if G::PAGED {
+            self.cursor.aligned_pages(G::LIMIT, concurrency.max(1))
+        } else {
+            self.cursor.pages(G::LIMIT, concurrency.max(1))
+        };
+        let results = try_join_all(
+            pages.iter().map(|page| self.gen.gen_counted(page.clone(), &self.this, &self.api))
+        ).await?;
+
+        let mut exhausted = false;
+        let mut result = Vec::new();
+        for ((mut data, len), page) in results.into_iter().zip(&pages) {
+            exhausted |= len < page.get_limit().unwrap_or_default();
+            result.append(&mut data);
+        }
+
+        if let Some(last) = pages.last() {
+            self.cursor.start = last.end.unwrap_or(last.start);
+        }
+        if exhausted || pages.is_empty() {
+            self.cursor.kill();
+        }
+        self.save_checkpoint();
+        Ok(result)
+    }
+
+    /// Collects the whole stream, fetching `concurrency` pages at the same time
+    /// - Pass a cursor with a known end (e.g. `(0, stats.remixes as usize)`) to avoid probing past the last page
+    pub async fn collect_parallel(&mut self, concurrency: usize) -> StreamResult<Self> {
+        let mut result = Vec::new();
+        while self.can_progress() {
+            result.append(&mut self.progress_parallel(concurrency).await?);
+        }
+        Ok(result)
+    }
 }
 
 #[async_trait]
@@ -72,11 +250,12 @@ impl<G: GeneralStreamGen + Clone + Send + Sync> Stream for GeneralStream<G> {
     type Data = G::Data;
     type Error = G::Error;
     async fn progress(&mut self) -> StreamResult<Self> {
-        let result = self.gen.gen(self.cursor.clone(), &self.this, &self.api).await?;
-        self.cursor.progress(result.len());
-        if result.is_empty() {
+        let (result, len) = self.gen.gen_counted(self.cursor.clone(), &self.this, &self.api).await?;
+        self.cursor.progress(len);
+        if len == 0 {
             self.cursor.kill();
         }
+        self.save_checkpoint();
         Ok(result)
     }
     fn can_progress(&self) -> bool {
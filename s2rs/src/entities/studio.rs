@@ -7,6 +7,9 @@ use crate::api::{self, Api, StudioInfo, SendComment};
 use super::{Project, StudioComment};
 #[cfg(feature = "stream")] use crate::cursor::Cursor;
 #[cfg(feature = "stream")] use super::{studio_stream::*, stream::GeneralStream};
+#[cfg(feature = "watch")] use super::{studio_watch::{self, StudioActivityWatchOptions}, StudioAction};
+use crate::date::Date;
+use crate::utils::meta_cache::MetaCache;
 
 // region: StudioWithTitle
 /// Extends [`Studio`] with it's title
@@ -20,8 +23,9 @@ use super::{Project, StudioComment};
 /// dbg![meta.title, meta.id];
 /// # })
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 #[deref(this)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StudioWithTitle {
     pub title: String,
     pub this: Arc<Studio>,
@@ -43,7 +47,7 @@ impl StudioWithTitle {
 
 // region: StudioMeta
 /// Studio metadata
-/// - Mapping for <https://api.scratch.mit.edu/studios/STUDIO-ID>
+/// - Mapping for <https://api.scratch.mit.edu/studios/STUDIO-ID> and every studio listing
 /// # Examples
 /// ```
 /// # tokio_test::block_on(async {
@@ -53,14 +57,15 @@ impl StudioWithTitle {
 /// let meta = studio.meta().await.unwrap();
 /// dbg![
 ///     &meta.history.modified,
-///     &meta.stats.projects,
+///     &meta.stats,
 ///     &meta.description
 ///     // ...
 /// ];
 /// # })
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[deref(this)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StudioMeta {
     pub this: Arc<StudioWithTitle>,
     pub host: u64,
@@ -71,7 +76,8 @@ pub struct StudioMeta {
     pub comments_allowed: bool,
     pub image: String,
     pub history: api::StudioHistory,
-    pub stats: api::StudioStats
+    /// `None` when listing doesn't include stats
+    pub stats: Option<api::StudioStats>
 }
 
 impl StudioMeta {
@@ -95,10 +101,10 @@ impl StudioMeta {
         Self::with_this(data, StudioWithTitle::with_this(title, this))
     }
 
+    /// Prefills the created [`Studio`] handle, so its [`Studio::meta`] won't hit the network
     pub fn new(data: api::Studio, api: Arc<Api>) -> Arc<Self> {
-        let title = data.title.clone();
-        let id = data.id;
-        Self::with_this(data, StudioWithTitle::new(title, id, api))
+        let this = Studio::with_meta(data.clone(), api);
+        Self::with_this_this(data, this)
     }
 
     pub fn vec_new(data: Vec<api::Studio>, api: Arc<Api>) -> Vec<Arc<Self>> {
@@ -108,58 +114,12 @@ impl StudioMeta {
 
 // endregion: StudioMeta
 
-// region: Studio2
-#[derive(Debug)]
-#[deref(this)]
-pub struct Studio2 {
-    pub this: Arc<StudioWithTitle>,
-    pub host: u64,
-    pub description: String,
-    pub visibility: String,
-    pub public: bool,
-    pub open_to_all: bool,
-    pub comments_allowed: bool,
-    pub image: String,
-    pub history: api::StudioHistory,
-}
-
-impl Studio2 {
-    pub fn with_this(data: api::Studio2, this: Arc<StudioWithTitle>) -> Arc<Self> {
-        Arc::new(Self {
-            this,
-            comments_allowed: data.comments_allowed,
-            description: data.description,
-            history: data.history,
-            host: data.host,
-            image: data.image,
-            open_to_all: data.open_to_all,
-            public: data.public,
-            visibility: data.visibility,
-        })
-    }
-
-    pub fn with_this_this(data: api::Studio2, this: Arc<Studio>) -> Arc<Self> {
-        let title = data.title.clone();
-        Self::with_this(data, StudioWithTitle::with_this(title, this))
-    }
-
-    pub fn new(data: api::Studio2, api: Arc<Api>) -> Arc<Self> {
-        let title = data.title.clone();
-        let id = data.id;
-        Self::with_this(data, StudioWithTitle::new(title, id, api))
-    }
-
-    pub fn vec_new(data: Vec<api::Studio2>, api: Arc<Api>) -> Vec<Arc<Self>> {
-        data.into_iter().map(|data| Self::new(data, api.clone())).collect()
-    }
-}
-// endregion: Studio2
-
 // region: AddStudioProject
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct AddStudioProject {
     pub this: Arc<Studio>,
-    pub created_at: String,
+    pub created_at: Date,
     pub project: Project,
 }
 // endregion: AddStudioProject
@@ -191,11 +151,16 @@ pub struct AddStudioProject {
 /// // ...
 /// # })
 /// ```
-#[derive(Derivative)]
+#[derive(Derivative, Clone)]
 #[derivative(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Studio {
     #[derivative(Debug="ignore", PartialEq="ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     api: Arc<Api>,
+    #[derivative(Debug="ignore", PartialEq="ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    meta: MetaCache<api::Studio>,
     pub id: u64,
 }
 
@@ -203,14 +168,25 @@ impl Studio {
     pub fn new(id: u64, api: Arc<Api>) -> Arc<Self> {
         Arc::new(Self {
             api,
+            meta: MetaCache::default(),
             id
         })
     }
+
+    fn with_meta(data: api::Studio, api: Arc<Api>) -> Arc<Self> {
+        Arc::new(Self {
+            api,
+            id: data.id,
+            meta: MetaCache::new(Some(data)),
+        })
+    }
 }
 
 impl Studio {
     /// Get studio metadata
     /// - Results in [`StudioMeta`]
+    /// - Fetched on the first call and cached in this handle, use [`Studio::refresh`] to fetch it again
+    /// - Handles coming from listings are prefilled, their `stats` can be `None` until [`Studio::refresh`]
     /// # Examples
     /// ```
     /// # tokio_test::block_on(async {
@@ -223,7 +199,28 @@ impl Studio {
     /// # })
     /// ```
     pub async fn meta(self: &Arc<Self>) -> Result<Arc<StudioMeta>, api::Error> {
-        Ok(StudioMeta::with_this_this(self.api.studio_meta(self.id).await?, self.clone()))
+        let data = match self.meta.get() {
+            Some(data) => data,
+            None => {
+                let data = self.api.studio_meta(self.id).await?;
+                self.meta.set(data.clone());
+                data
+            }
+        };
+        Ok(StudioMeta::with_this_this(data, self.clone()))
+    }
+
+    /// Same as [`Studio::meta`], but always fetches fresh metadata and replaces the cached one
+    pub async fn refresh(self: &Arc<Self>) -> Result<Arc<StudioMeta>, api::Error> {
+        let data = self.api.refresh_studio_meta(self.id).await?;
+        self.meta.set(data.clone());
+        Ok(StudioMeta::with_this_this(data, self.clone()))
+    }
+
+    /// Removes cached metadata, next [`Studio::meta`] call will hit the network
+    pub fn invalidate_meta(&self) {
+        self.meta.clear();
+        self.api.invalidate_studio_meta(self.id)
     }
 
     pub fn comment(self: &Arc<Self>, id: u64) -> Arc<StudioComment> {
@@ -332,6 +329,30 @@ impl Studio {
         GeneralStream::with_this(StudioActivity, cursor.into(), self.clone(), self.api.clone())
     }
 
+    /// Watch studio activity for new actions
+    /// - `Requires crate feature: 'watch'`
+    /// - Errors are yielded without stopping the stream
+    /// # Examples
+    /// ```
+    /// # tokio_test::block_on(async {
+    /// # use s2rs::session::Session;
+    /// use futures_util::StreamExt;
+    /// use s2rs::entities::{StudioActivityWatchOptions, StudioActionEvent};
+    /// # let session = Session::new("YourUsername");
+    /// let studio = session.studio(32774157);
+    /// let mut actions = Box::pin(studio.watch_activity(StudioActivityWatchOptions::default()));
+    /// while let Some(action) = actions.next().await {
+    ///     if let StudioActionEvent::AddProject(project) = &action.unwrap().event {
+    ///         dbg![project];
+    ///     }
+    /// }
+    /// # })
+    /// ```
+    #[cfg(feature = "watch")]
+    pub fn watch_activity(self: &Arc<Self>, options: StudioActivityWatchOptions) -> impl futures_util::Stream<Item = Result<Arc<StudioAction>, api::GetStudioActivityError>> + Send {
+        studio_watch::watch_activity(self.clone(), self.api.clone(), options)
+    }
+
     /// Invite someone to the studio
     /// - Requires authentication
     /// # Examples
@@ -1,13 +1,15 @@
 use super::{UserWithId, User, ProjectWithTitle};
 use std::sync::Arc;
 use crate::api::{Api, self};
+use crate::date::Date;
 
 // region: StudioAction
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StudioAction {
     pub id: u64,
     pub actor: Arc<UserWithId>,
-    pub created_at: String,
+    pub created_at: Date,
     pub event: StudioActionEvent,
 }
 
@@ -28,13 +30,20 @@ impl StudioAction {
 // endregion: StudioAction
 
 // region: StudioActionEvent
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum StudioActionEvent {
     Update,
     AddProject(Arc<ProjectWithTitle>),
     RemoveProject(Arc<ProjectWithTitle>),
     AcceptInvite(Arc<User>),
     Promote(Arc<User>),
+    PromoteHost(Arc<User>),
+    RemoveCurator(Arc<User>),
+    Unknown {
+        event_type: String,
+        raw: serde_json::Value,
+    }
 }
 
 impl StudioActionEvent {
@@ -52,7 +61,14 @@ impl StudioActionEvent {
             api::StudioActionEvent::RemoveProject { id, title } => Self::RemoveProject(
                 ProjectWithTitle::new(title, id, api)
             ),
-            api::StudioActionEvent::Update => Self::Update
+            api::StudioActionEvent::PromoteHost { name } => Self::PromoteHost(
+                User::new(name, api)
+            ),
+            api::StudioActionEvent::RemoveCurator { name } => Self::RemoveCurator(
+                User::new(name, api)
+            ),
+            api::StudioActionEvent::Update => Self::Update,
+            api::StudioActionEvent::Unknown { event_type, raw } => Self::Unknown { event_type, raw }
         }
     }
 }
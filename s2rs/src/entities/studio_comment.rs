@@ -6,15 +6,18 @@ use crate::api::{self, Api, SendComment};
 use super::{Studio, CommentAuthor};
 #[cfg(feature = "stream")] use super::{stream::GeneralStream, StudioCommentReplies};
 #[cfg(feature = "stream")] use crate::cursor::Cursor;
+use crate::date::Date;
 
 // region: StudioComment
 #[allow(unused)]
-#[derive(Derivative)]
+#[derive(Derivative, Clone)]
 #[derivative(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StudioComment {
     pub id: u64,
     pub at: Arc<Studio>,
     #[derivative(Debug = "ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     api: Arc<Api>
 }
 
@@ -43,16 +46,17 @@ impl StudioComment {
 // endregion: StudioComment
 
 // region: StudioCommentMeta
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[deref(this)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StudioCommentMeta {
     pub this: Arc<StudioComment>,
     pub author: CommentAuthor,
     pub parent: Option<Arc<StudioComment>>,
     pub to_user_id: Option<u64>,
     pub content: String,
-    pub created_at: String,
-    pub modified_at: String,
+    pub created_at: Date,
+    pub modified_at: Date,
     pub reply_count: u64,
 }
 
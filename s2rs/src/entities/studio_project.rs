@@ -2,7 +2,8 @@ use std::sync::Arc;
 use crate::api::{Api, self};
 use super::{User, ProjectWithTitle};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StudioProject {
     pub this: Arc<ProjectWithTitle>,
     pub image: String,
@@ -0,0 +1,89 @@
+use std::{sync::Arc, collections::VecDeque};
+use futures_util::Stream;
+use crate::{api::{self, Api}, Cursor};
+use async_trait::async_trait;
+use super::{Studio, StudioAction, watch::{AdaptiveInterval, Poller, watch_stream}};
+
+// region: StudioActivityWatch
+#[derive(Debug, Clone)]
+pub struct StudioActivityWatchOptions {
+    pub interval: AdaptiveInterval,
+    /// Id of the last handled action, actions after it are emitted on the first poll
+    /// - When `None` only actions made after watching started are emitted
+    pub last_id: Option<u64>,
+    /// Limit of pages fetched at once when lots of actions happened between polls
+    pub max_pages: usize,
+}
+
+impl Default for StudioActivityWatchOptions {
+    fn default() -> Self {
+        Self {
+            interval: AdaptiveInterval::default(),
+            last_id: None,
+            max_pages: 5
+        }
+    }
+}
+
+struct StudioActivityWatch {
+    studio: Arc<Studio>,
+    api: Arc<Api>,
+    options: StudioActivityWatchOptions,
+    pending: VecDeque<Arc<StudioAction>>,
+}
+
+#[async_trait]
+impl Poller for StudioActivityWatch {
+    type Item = Arc<StudioAction>;
+    type Error = api::GetStudioActivityError;
+
+    async fn poll(&mut self, _first: bool) -> Result<(), api::GetStudioActivityError> {
+        let mut actions = Vec::new();
+        // activity comes newest first
+        'pages: for page in Cursor::new(0, None).pages(40, self.options.max_pages.max(1)) {
+            let data = self.api.studio_activity(self.studio.id, page).await?;
+            let full = data.len() == 40;
+            for action in data {
+                if self.options.last_id.is_some_and(|last_id| action.id <= last_id) {
+                    break 'pages
+                }
+                actions.push(action);
+            }
+            if !full || self.options.last_id.is_none() {
+                break
+            }
+        }
+
+        let newest = actions.first().map(|action| action.id);
+        if self.options.last_id.is_some() {
+            self.pending.extend(StudioAction::vec_new(actions, self.api.clone()).into_iter().rev());
+        }
+        if let Some(newest) = newest {
+            self.options.last_id = Some(newest);
+            self.options.interval.reset();
+        } else {
+            self.options.interval.back_off();
+        }
+        Ok(())
+    }
+
+    fn pending(&mut self) -> Option<Result<Self::Item, Self::Error>> {
+        self.pending.pop_front().map(Ok)
+    }
+
+    fn interval(&mut self) -> &mut AdaptiveInterval {
+        &mut self.options.interval
+    }
+}
+
+pub(super) fn watch_activity(studio: Arc<Studio>, api: Arc<Api>, options: StudioActivityWatchOptions) -> impl Stream<Item = Result<Arc<StudioAction>, api::GetStudioActivityError>> + Send {
+    let state = StudioActivityWatch {
+        studio,
+        api,
+        options,
+        pending: VecDeque::new(),
+    };
+
+    watch_stream(state)
+}
+// endregion: StudioActivityWatch
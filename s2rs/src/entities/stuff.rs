@@ -0,0 +1,104 @@
+//! Own projects and studios listed in My Stuff
+
+use std::sync::Arc;
+use s2rs_derive::deref;
+use crate::api::{self, Api};
+use crate::date::Date;
+use super::{ProjectWithTitle, StudioWithTitle, UserWithId};
+
+// region: StuffProject
+/// Project from My Stuff
+/// - Mapping for <https://scratch.mit.edu/site-api/projects/all/>
+#[derive(Debug, Clone)]
+#[deref(this)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StuffProject {
+    pub this: Arc<ProjectWithTitle>,
+    pub author: Arc<UserWithId>,
+    pub created_at: Date,
+    pub modified_at: Date,
+    /// `None` for projects which were never shared
+    pub shared_at: Option<Date>,
+    pub public: bool,
+    pub visibility: String,
+    pub thumbnail_url: String,
+    pub view_count: u32,
+    pub love_count: u32,
+    pub favorite_count: u32,
+    pub remix_count: u32,
+    pub commenters_count: u64,
+}
+
+impl StuffProject {
+    pub fn with_this(data: api::StuffProject, this: Arc<ProjectWithTitle>, api: Arc<Api>) -> Arc<Self> {
+        let data = data.fields;
+        Arc::new(Self {
+            this,
+            author: UserWithId::new(data.author.id, data.author.name, api),
+            created_at: data.created_at,
+            modified_at: data.modified_at,
+            shared_at: data.shared_at,
+            public: data.public,
+            visibility: data.visibility,
+            thumbnail_url: data.thumbnail_url,
+            view_count: data.view_count,
+            love_count: data.love_count,
+            favorite_count: data.favorite_count,
+            remix_count: data.remix_count,
+            commenters_count: data.commenters_count,
+        })
+    }
+
+    pub fn new(data: api::StuffProject, api: Arc<Api>) -> Arc<Self> {
+        let this = ProjectWithTitle::new(data.fields.title.clone(), data.id, api.clone());
+        Self::with_this(data, this, api)
+    }
+
+    pub fn vec_new(data: Vec<api::StuffProject>, api: Arc<Api>) -> Vec<Arc<Self>> {
+        data.into_iter().map(|data| Self::new(data, api.clone())).collect()
+    }
+}
+// endregion: StuffProject
+
+// region: StuffStudio
+/// Studio from My Stuff
+/// - Mapping for <https://scratch.mit.edu/site-api/galleries/all/>
+#[derive(Debug, Clone)]
+#[deref(this)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StuffStudio {
+    pub this: Arc<StudioWithTitle>,
+    pub owner: Arc<UserWithId>,
+    pub created_at: Date,
+    pub modified_at: Date,
+    pub thumbnail_url: String,
+    pub curator_count: u32,
+    pub projects_count: u32,
+    pub commenters_count: u64,
+}
+
+impl StuffStudio {
+    pub fn with_this(data: api::StuffStudio, this: Arc<StudioWithTitle>, api: Arc<Api>) -> Arc<Self> {
+        let data = data.fields;
+        Arc::new(Self {
+            this,
+            owner: UserWithId::new(data.owner.id, data.owner.name, api),
+            created_at: data.created_at,
+            modified_at: data.modified_at,
+            thumbnail_url: data.thumbnail_url,
+            curator_count: data.curator_count,
+            projects_count: data.projects_count,
+            commenters_count: data.commenters_count,
+        })
+    }
+
+    pub fn new(data: api::StuffStudio, api: Arc<Api>) -> Arc<Self> {
+        let this = StudioWithTitle::new(data.fields.title.clone(), data.id, api.clone());
+        Self::with_this(data, this, api)
+    }
+
+    pub fn vec_new(data: Vec<api::StuffStudio>, api: Arc<Api>) -> Vec<Arc<Self>> {
+        data.into_iter().map(|data| Self::new(data, api.clone())).collect()
+    }
+}
+// endregion: StuffStudio
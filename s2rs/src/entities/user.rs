@@ -2,11 +2,11 @@
 use std::sync::Arc;
 use derivative::Derivative;
 use s2rs_derive::deref;
-use crate::{api::{Api, self, UserNameCheck, SendComment}, utils::into_arc::IntoArc};
+use crate::{api::{Api, self, UserNameCheck, SendComment}, utils::{into_arc::IntoArc, meta_cache::MetaCache}};
 use super::{UserFeatured, UserProject, UserComment};
 #[cfg(feature = "stream")] use super::{stream::GeneralStream, user_stream::*};
 #[cfg(feature = "stream")] use crate::cursor::Cursor;
-#[cfg(feature = "html")] use super::UserCommentMeta;
+#[cfg(feature = "html")] use super::{UserCommentMeta, UserCommentEntry};
 
 // region: UserMeta
 /// General user metadata
@@ -21,8 +21,9 @@ use super::{UserFeatured, UserProject, UserComment};
 /// dbg!(&meta_data.profile.bio);
 /// # })
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[deref(this)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserMeta {
     pub this: Arc<UserWithId>,
     pub scratch_team: bool,
@@ -45,10 +46,10 @@ impl UserMeta {
         Self::with_this(data, UserWithId::with_this(id, this, api))
     }
 
+    /// Prefills the created [`User`] handle, so its [`User::meta`] won't hit the network
     pub fn new(data: api::User, api: Arc<Api>) -> Arc<Self> {
-        let name = data.name.clone();
-        let id = data.id;
-        Self::with_this(data, UserWithId::new(id, name, api))
+        let this = User::with_meta(data.clone(), api.clone());
+        Self::with_this_this(data, this, api)
     }
 
     pub fn vec_new(data: Vec<api::User>, api: Arc<Api>) -> Vec<Arc<Self>> {
@@ -69,13 +70,15 @@ impl UserMeta {
 /// dbg!(&meta_data.profile.bio);
 /// # })
 /// ```
-#[derive(Derivative)]
+#[derive(Derivative, Clone)]
 #[derivative(Debug)]
 #[deref(this)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserWithId {
     pub this: Arc<User>,
     pub id: u64,
     #[derivative(Debug = "ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     api: Arc<Api>
 }
 
@@ -110,11 +113,16 @@ impl UserWithId {
 /// // ...
 /// # })
 /// ```
-#[derive(Derivative)]
+#[derive(Derivative, Clone)]
 #[derivative(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct User {
     #[derivative(Debug="ignore", PartialEq="ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     api: Arc<Api>,
+    #[derivative(Debug="ignore", PartialEq="ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    meta: MetaCache<api::User>,
     pub name: Arc<String>,
 }
 
@@ -122,13 +130,23 @@ impl User {
     pub fn new(name: impl IntoArc<String>, api: Arc<Api>) -> Arc<Self> {
         Arc::new(Self {
             api,
+            meta: MetaCache::default(),
             name: name.into_arc()
         })
     }
+
+    fn with_meta(data: api::User, api: Arc<Api>) -> Arc<Self> {
+        Arc::new(Self {
+            api,
+            name: data.name.clone().into_arc(),
+            meta: MetaCache::new(Some(data)),
+        })
+    }
 }
 
 impl User {
     /// User's meta-data
+    /// - Fetched on the first call and cached in this handle, use [`User::refresh`] to fetch it again
     /// # Examples
     /// ```
     /// # tokio_test::block_on(async {
@@ -140,7 +158,28 @@ impl User {
     /// # })
     /// ```
     pub async fn meta(self: &Arc<Self>) -> Result<Arc<UserMeta>, api::Error> {
-        Ok(UserMeta::with_this_this(self.api.user_meta(&self.name).await?, self.clone(), self.api.clone()))
+        let data = match self.meta.get() {
+            Some(data) => data,
+            None => {
+                let data = self.api.user_meta(&self.name).await?;
+                self.meta.set(data.clone());
+                data
+            }
+        };
+        Ok(UserMeta::with_this_this(data, self.clone(), self.api.clone()))
+    }
+
+    /// Same as [`User::meta`], but always fetches fresh metadata and replaces the cached one
+    pub async fn refresh(self: &Arc<Self>) -> Result<Arc<UserMeta>, api::Error> {
+        let data = self.api.refresh_user_meta(&self.name).await?;
+        self.meta.set(data.clone());
+        Ok(UserMeta::with_this_this(data, self.clone(), self.api.clone()))
+    }
+
+    /// Removes cached metadata, next [`User::meta`] call will hit the network
+    pub fn invalidate_meta(&self) {
+        self.meta.clear();
+        self.api.invalidate_user_meta(&self.name)
     }
 
     pub fn comment(self: &Arc<Self>, id: u64) -> Arc<UserComment> {
@@ -277,10 +316,31 @@ impl User {
         GeneralStream::with_this(UserFollowingActivity, cursor.into(), self.clone(), self.api.clone())
     }
 
-    // #[cfg(feature = "stream")]
+    /// Comments on user's profile
+    /// - Comments which fail to parse are left out and collected in [`UserComments::skipped`]
+    /// # Examples
+    /// ```
+    /// # tokio_test::block_on(async {
+    /// # use s2rs::session::Session;
+    /// # use s2rs::entities::Stream;
+    /// # let session = Session::new("YourUsername");
+    /// let user = session.user("griffpatch");
+    /// let mut comments = user.comments((0, 80));
+    /// for comment in comments.collect().await.unwrap() {
+    ///     dbg![ comment.content.text(), comment.reply_count ];
+    /// }
+    /// dbg![ comments.gen().skipped.take() ];
+    /// # })
+    /// ```
+    #[cfg(all(feature = "stream", feature = "html"))]
+    pub fn comments(self: &Arc<Self>, cursor: impl Into<Cursor>) -> GeneralStream<UserComments> {
+        GeneralStream::with_this(UserComments::default(), cursor.into(), self.clone(), self.api.clone())
+    }
+
+    /// One page of comments on user's profile, `page` starts at 1
     #[cfg(feature = "html")]
-    pub async fn comments(self: &Arc<Self>, page: Option<u8>) -> Result<Vec<Arc<UserCommentMeta>>, api::GetUserCommentsError> {
-        Ok(UserCommentMeta::vec_with_profile(self.api.user_comments(&self.name, page).await?, self.clone(), self.api.clone()))
+    pub async fn comments_page(self: &Arc<Self>, page: u32) -> Result<Vec<UserCommentEntry>, api::GetUserCommentsError> {
+        Ok(UserCommentMeta::vec_with_profile(self.api.user_comments(&self.name, Some(page)).await?, self.clone(), self.api.clone()))
     }
 
     pub async fn follow(&self) -> Result<(), api::Error> {
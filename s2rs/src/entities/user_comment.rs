@@ -3,8 +3,12 @@ use s2rs_derive::deref;
 use crate::api::{self, CommentContent, SendComment};
 use super::{Api, UserWithId, User};
 use std::sync::Arc;
+use crate::date::Date;
+#[cfg(all(feature = "stream", feature = "html"))] use super::{stream::GeneralStream, user_stream::UserCommentReplies};
+#[cfg(all(feature = "stream", feature = "html"))] use crate::cursor::Cursor;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum EmojiVariant {
     Meow,
     Taco,
@@ -39,13 +43,15 @@ pub enum EmojiVariant {
 }
 
 // region: UserComment
-#[derive(Derivative)]
+#[derive(Derivative, Clone)]
 #[derivative(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserComment {
     pub id: u64,
     #[derivative(PartialEq = "ignore")]
     pub profile: Arc<User>,
     #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     api: Arc<Api>,
 }
 
@@ -66,6 +72,13 @@ impl UserComment {
         self.api.report_user_comment(self.id).await
     }
 
+    /// Deletes the comment, only works on own profile
+    pub async fn delete(&self) -> api::Result<()> {
+        self.api.delete_user_comment(&self.profile.name, self.id).await
+    }
+
+    /// Replies to the comment thread
+    /// - `to_id` is id of the user being replied to, see [`UserCommentMeta::reply_to_author`]
     pub async fn reply(&self, content: impl Into<String>, to_id: Option<u64>) -> api::Result<()> {
         self.api.send_user_comment(&self.profile.name, &SendComment {
             content: content.into(),
@@ -73,25 +86,38 @@ impl UserComment {
             parent_id: Some(self.id)
         }).await
     }
+
+    /// Whole reply thread, unlike [`UserCommentMeta::replies`] which only has replies shown along with the comment
+    /// - Replies which fail to parse are left out and collected in [`UserCommentReplies::skipped`]
+    #[cfg(all(feature = "stream", feature = "html"))]
+    pub fn replies(self: &Arc<Self>, cursor: impl Into<Cursor>) -> GeneralStream<UserCommentReplies> {
+        GeneralStream::with_this(UserCommentReplies::default(), cursor.into(), self.clone(), self.api.clone())
+    }
 }
 // endregion: UserComment
 
 // region: UserCommentMeta
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[deref(this)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserCommentMeta {
     pub this: Arc<UserComment>,
     pub author: Arc<UserWithId>,
     pub avatar_url: String,
-    pub created_at: String,
+    pub created_at: Date,
     pub content: CommentContent,
-    pub replies: Vec<Arc<UserReplyMeta>>,
+    pub replies: Vec<UserReplyEntry>,
+    pub reply_count: u32,
 }
 
+/// Profile comment, or the reason it couldn't be parsed
+pub type UserCommentEntry = Result<Arc<UserCommentMeta>, api::ParseUserCommentError>;
+
 impl UserCommentMeta {
     pub fn with_this(data: api::UserComment, this: Arc<UserComment>, api: Arc<Api>) -> Arc<Self> {
         Arc::new(Self {
             replies: UserReplyMeta::vec_with_profile(data.replies, this.profile.clone(), api.clone()),
+            reply_count: data.reply_count,
             this,
             author: UserWithId::new(data.author_id, data.author_name, api),
             avatar_url: data.avatar_url,
@@ -105,40 +131,72 @@ impl UserCommentMeta {
         Self::with_this(data, UserComment::with_profile(id, profile, api.clone()), api)
     }
 
-    pub fn vec_with_profile(data: Vec<api::UserComment>, profile: Arc<User>, api: Arc<Api>) -> Vec<Arc<Self>> {
-        data.into_iter().map(|data| Self::with_profile(data, profile.clone(), api.clone())).collect()
+    pub fn vec_with_profile(data: Vec<Result<api::UserComment, api::ParseUserCommentError>>, profile: Arc<User>, api: Arc<Api>) -> Vec<UserCommentEntry> {
+        data.into_iter().map(|data| Ok(Self::with_profile(data?, profile.clone(), api.clone()))).collect()
+    }
+
+    /// Replies to the comment, notifying its author
+    pub async fn reply_to_author(&self, content: impl Into<String>) -> api::Result<()> {
+        self.reply(content, Some(self.author.id)).await
     }
 }
 // endregion: UserCommentMeta
 
 // region: UserReply
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Derivative, Clone)]
+#[derivative(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserReply {
     pub id: u64,
+    /// Id of the top level comment
+    pub parent_id: u64,
+    #[derivative(PartialEq = "ignore")]
     pub profile: Arc<User>,
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    api: Arc<Api>,
 }
 
 impl UserReply {
-    pub fn with_profile(id: u64, profile: Arc<User>) -> Arc<Self> {
+    pub fn with_profile(id: u64, parent_id: u64, profile: Arc<User>, api: Arc<Api>) -> Arc<Self> {
         Arc::new(Self {
             id,
-            profile
+            parent_id,
+            profile,
+            api
         })
     }
+
+    pub fn parent(&self) -> Arc<UserComment> {
+        UserComment::with_profile(self.parent_id, self.profile.clone(), self.api.clone())
+    }
+
+    pub async fn report(&self) -> api::Result<()> {
+        self.api.report_user_comment(self.id).await
+    }
+
+    /// Deletes the reply, only works on own profile
+    pub async fn delete(&self) -> api::Result<()> {
+        self.api.delete_user_comment(&self.profile.name, self.id).await
+    }
 }
 // endregion: UserReply
 
 // region: UserReplyMeta
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[deref(this)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserReplyMeta {
     pub this: Arc<UserReply>,
     pub author: Arc<UserWithId>,
     pub avatar_url: String,
     pub content: CommentContent,
-    pub created_at: String,
+    pub created_at: Date,
 }
 
+/// Profile comment reply, or the reason it couldn't be parsed
+pub type UserReplyEntry = Result<Arc<UserReplyMeta>, api::ParseUserCommentError>;
+
 impl UserReplyMeta {
     pub fn with_this(data: api::UserReply, this: Arc<UserReply>, api: Arc<Api>) -> Arc<Self> {
         Arc::new(Self {
@@ -152,11 +210,17 @@ impl UserReplyMeta {
 
     pub fn with_profile(data: api::UserReply, profile: Arc<User>, api: Arc<Api>) -> Arc<Self> {
         let id = data.id;
-        Self::with_this(data, UserReply::with_profile(id, profile), api)
+        let parent_id = data.parent_id;
+        Self::with_this(data, UserReply::with_profile(id, parent_id, profile, api.clone()), api)
+    }
+
+    pub fn vec_with_profile(data: Vec<Result<api::UserReply, api::ParseUserCommentError>>, profile: Arc<User>, api: Arc<Api>) -> Vec<UserReplyEntry> {
+        data.into_iter().map(|data| Ok(Self::with_profile(data?, profile.clone(), api.clone()))).collect()
     }
 
-    pub fn vec_with_profile(data: Vec<api::UserReply>, profile: Arc<User>, api: Arc<Api>) -> Vec<Arc<Self>> {
-        data.into_iter().map(|data| Self::with_profile(data, profile.clone(), api.clone())).collect()
+    /// Replies in the same thread, notifying author of this reply
+    pub async fn reply(&self, content: impl Into<String>) -> api::Result<()> {
+        self.parent().reply(content, Some(self.author.id)).await
     }
 }
 // endregion: UserReplyMeta
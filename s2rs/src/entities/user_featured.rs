@@ -1,8 +1,10 @@
 use std::sync::Arc;
 use super::{UserWithId, User, ProjectWithTitle};
 use crate::{api::{self, FeaturedLabel}, Api};
+use crate::date::Date;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserFeatured {
     pub id: u64, // not sure what this field is for
     pub label: FeaturedLabel,
@@ -25,11 +27,12 @@ impl UserFeatured {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserFeaturedProject {
     pub this: Arc<ProjectWithTitle>,
     pub thumbnail_url: String,
-    pub modified_at: String,
+    pub modified_at: Date,
 }
 
 impl UserFeaturedProject {
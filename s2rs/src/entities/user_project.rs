@@ -5,16 +5,19 @@ use derivative::Derivative;
 use crate::api::{self, Api};
 #[cfg(feature = "stream")] use super::{stream::GeneralStream, UserProjectCommentReplies, UserProjectComments};
 #[cfg(feature = "stream")] use crate::cursor::Cursor;
+use crate::date::Date;
 
 // region: UserProject
 #[allow(unused)]
 #[deref(this)]
-#[derive(Derivative)]
+#[derive(Derivative, Clone)]
 #[derivative(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserProject {
     pub this: Arc<Project>,
     pub author: Arc<User>,
     #[derivative(Debug = "ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     api: Arc<Api>
 }
 
@@ -35,8 +38,9 @@ impl UserProject {
 // endregion: UserProject
 
 // region: CommentAuthor
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[deref(this)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CommentAuthor {
     pub this: Arc<UserWithId>,
     pub scratch_team: bool,
@@ -57,12 +61,14 @@ impl CommentAuthor {
 // region: UserProjectComment
 #[allow(unused)]
 #[deref(this)]
-#[derive(Derivative)]
+#[derive(Derivative, Clone)]
 #[derivative(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserProjectComment {
     pub this: Arc<ProjectComment>,
     pub at: Arc<UserProject>,
     #[derivative(Debug = "ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     api: Arc<Api>,
 }
 
@@ -83,16 +89,17 @@ impl UserProjectComment {
 // endregion: UserProjectComment
 
 // region: UserProjectCommentMeta
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[deref(this)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserProjectCommentMeta {
     pub this: Arc<UserProjectComment>,
     pub author: CommentAuthor,
     pub parent: Option<Arc<UserProjectComment>>,
     pub to_user_id: Option<u64>,
     pub content: String,
-    pub created_at: String,
-    pub modified_at: String,
+    pub created_at: Date,
+    pub modified_at: Date,
     pub reply_count: u64,
 }
 
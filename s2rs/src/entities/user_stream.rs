@@ -1,17 +1,19 @@
 use std::sync::Arc;
 use crate::api::{self, Api};
-use super::{User, stream::{GeneralStreamResult, GeneralStreamGen}, Project3, UserProject, Studio2, UserMeta, FollowingAction, UserProjectCommentMeta, UserProjectComment};
+use super::{User, stream::{GeneralStreamResult, GeneralStreamGen}, ProjectMeta, UserProject, StudioMeta, UserMeta, FollowingAction, UserProjectCommentMeta, UserProjectComment};
 use async_trait::async_trait;
 use crate::cursor::Cursor;
+#[cfg(feature = "html")] use super::stream::{page_of, trim_page, SkippedEntries};
+#[cfg(feature = "html")] use super::{UserCommentMeta, UserReplyMeta, UserComment};
 
 // region: UserProjects
 #[derive(Clone)] pub struct UserProjects;
 #[async_trait] impl GeneralStreamGen for UserProjects {
-    type Data = Project3;
+    type Data = ProjectMeta;
     type Error = api::Error;
     type This = User;
     async fn gen(&self, cursor: Cursor, this: &Arc<Self::This>, api: &Arc<Api>) -> GeneralStreamResult<Self> {
-        Ok(Project3::vec_new(api.user_projects(&this.name, cursor).await?, api.clone()))
+        Ok(ProjectMeta::vec_with_author(api.user_projects(&this.name, cursor).await?, this.clone(), api.clone()))
     }
 }
 // endregion: UserProjects
@@ -19,11 +21,11 @@ use crate::cursor::Cursor;
 // region: UserFavorites
 #[derive(Clone)] pub struct UserFavorites;
 #[async_trait] impl GeneralStreamGen for UserFavorites {
-    type Data = Project3;
+    type Data = ProjectMeta;
     type Error = api::Error;
     type This = User;
     async fn gen(&self, cursor: Cursor, this: &Arc<Self::This>, api: &Arc<Api>) -> GeneralStreamResult<Self> {
-        Ok(Project3::vec_new(api.user_favorites(&this.name, cursor).await?, api.clone()))
+        Ok(ProjectMeta::vec_new(api.user_favorites(&this.name, cursor).await?, api.clone()))
     }
 }
 // endregion: UserFavorites
@@ -31,11 +33,11 @@ use crate::cursor::Cursor;
 // region: UserCuratingStudios
 #[derive(Clone)] pub struct UserCuratingStudios;
 #[async_trait] impl GeneralStreamGen for UserCuratingStudios {
-    type Data = Studio2;
+    type Data = StudioMeta;
     type Error = api::Error;
     type This = User;
     async fn gen(&self, cursor: Cursor, this: &Arc<Self::This>, api: &Arc<Api>) -> GeneralStreamResult<Self> {
-        Ok(Studio2::vec_new(api.user_curating_studios(&this.name, cursor).await?, api.clone()))
+        Ok(StudioMeta::vec_new(api.user_curating_studios(&this.name, cursor).await?, api.clone()))
     }
 }
 // endregion: UserCuratingStudios
@@ -89,19 +91,53 @@ use crate::cursor::Cursor;
 // endregion: UserFollowingActivity
 
 // region: UserComments
-// #[cfg(feature = "html")]
-// #[derive(Clone)] pub struct UserComments;
-// #[cfg(feature = "html")]
-// #[async_trait] impl GeneralStreamGen for UserComments {
-//     type Data = UserCommentMeta;
-//     type Error = api::GetUserCommentsError;
-//     type This = User;
-//     async fn gen(&self, cursor: Cursor, this: &Arc<Self::This>, api: &Arc<Api>) -> GeneralStreamResult<Self> {
-//         Ok(UserCommentMeta::vec_with_profile(api.user_comments(&this.name, Some(cursor.start)).await?, this.clone(), api.clone()))
-//     }
-// }
+#[cfg(feature = "html")]
+#[derive(Clone, Default)] pub struct UserComments {
+    pub skipped: SkippedEntries<api::ParseUserCommentError>,
+}
+#[cfg(feature = "html")]
+#[async_trait] impl GeneralStreamGen for UserComments {
+    type Data = UserCommentMeta;
+    type Error = api::GetUserCommentsError;
+    type This = User;
+    const LIMIT: usize = api::USER_COMMENTS_PAGE_LIMIT;
+    const PAGED: bool = true;
+    async fn gen(&self, cursor: Cursor, this: &Arc<Self::This>, api: &Arc<Api>) -> GeneralStreamResult<Self> {
+        Ok(self.gen_counted(cursor, this, api).await?.0)
+    }
+    async fn gen_counted(&self, cursor: Cursor, this: &Arc<Self::This>, api: &Arc<Api>) -> Result<(Vec<Arc<Self::Data>>, usize), Self::Error> {
+        let data = api.user_comments(&this.name, Some(page_of(&cursor, Self::LIMIT))).await?;
+        let data = UserCommentMeta::vec_with_profile(trim_page(data, &cursor, Self::LIMIT), this.clone(), api.clone());
+        let len = data.len();
+        Ok((self.skipped.split(data), len))
+    }
+}
 // endregion: UserComments
 
+// region: UserCommentReplies
+#[cfg(feature = "html")]
+#[derive(Clone, Default)] pub struct UserCommentReplies {
+    pub skipped: SkippedEntries<api::ParseUserCommentError>,
+}
+#[cfg(feature = "html")]
+#[async_trait] impl GeneralStreamGen for UserCommentReplies {
+    type Data = UserReplyMeta;
+    type Error = api::GetUserCommentsError;
+    type This = UserComment;
+    const LIMIT: usize = api::USER_COMMENTS_PAGE_LIMIT;
+    const PAGED: bool = true;
+    async fn gen(&self, cursor: Cursor, this: &Arc<Self::This>, api: &Arc<Api>) -> GeneralStreamResult<Self> {
+        Ok(self.gen_counted(cursor, this, api).await?.0)
+    }
+    async fn gen_counted(&self, cursor: Cursor, this: &Arc<Self::This>, api: &Arc<Api>) -> Result<(Vec<Arc<Self::Data>>, usize), Self::Error> {
+        let data = api.user_comment_replies(&this.profile.name, this.id, Some(page_of(&cursor, Self::LIMIT))).await?;
+        let data = UserReplyMeta::vec_with_profile(trim_page(data, &cursor, Self::LIMIT), this.profile.clone(), api.clone());
+        let len = data.len();
+        Ok((self.skipped.split(data), len))
+    }
+}
+// endregion: UserCommentReplies
+
 // region: UserProjectComments
 #[derive(Clone)] pub struct UserProjectComments;
 #[async_trait] impl GeneralStreamGen for UserProjectComments {
@@ -0,0 +1,133 @@
+use std::{collections::{HashSet, VecDeque}, hash::Hash, time::Duration};
+use async_trait::async_trait;
+use futures_util::Stream;
+
+// region: AdaptiveInterval
+/// Polling interval which grows while nothing changes and drops back to `min` on activity
+#[derive(Debug, Clone)]
+pub struct AdaptiveInterval {
+    pub min: Duration,
+    pub max: Duration,
+    current: Duration,
+}
+
+impl AdaptiveInterval {
+    pub fn new(min: Duration, max: Duration) -> Self {
+        Self {
+            min,
+            max: max.max(min),
+            current: min
+        }
+    }
+
+    pub fn current(&self) -> Duration {
+        self.current
+    }
+
+    /// Something changed, poll often again
+    pub fn reset(&mut self) {
+        self.current = self.min;
+    }
+
+    /// Nothing changed, poll less often
+    pub fn back_off(&mut self) {
+        self.current = (self.current * 2).min(self.max);
+    }
+
+    pub async fn wait(&self) {
+        tokio::time::sleep(self.current).await
+    }
+}
+
+impl Default for AdaptiveInterval {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(5), Duration::from_secs(60))
+    }
+}
+// endregion: AdaptiveInterval
+
+// region: SeenSet
+/// Remembers last `capacity` ids to filter out already emitted items
+#[derive(Debug, Clone)]
+pub struct SeenSet<T: Hash + Eq + Clone> {
+    capacity: usize,
+    items: HashSet<T>,
+    order: VecDeque<T>,
+}
+
+impl<T: Hash + Eq + Clone> SeenSet<T> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            items: HashSet::new(),
+            order: VecDeque::new()
+        }
+    }
+
+    /// Returns `true` if `item` wasn't seen before
+    pub fn insert(&mut self, item: T) -> bool {
+        if !self.items.insert(item.clone()) {
+            return false
+        }
+        self.order.push_back(item);
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.items.remove(&oldest);
+            }
+        }
+        true
+    }
+
+    pub fn contains(&self, item: &T) -> bool {
+        self.items.contains(item)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.order.iter()
+    }
+}
+
+impl<T: Hash + Eq + Clone> Default for SeenSet<T> {
+    fn default() -> Self {
+        Self::new(1000)
+    }
+}
+// endregion: SeenSet
+
+// region: Poller
+/// State of a watcher driven by [`watch_stream`]
+#[async_trait]
+pub(crate) trait Poller: Send + 'static {
+    type Item: Send;
+    type Error: Send;
+
+    /// Fetches new items, making them available through [`Poller::pending`]
+    /// - Should `reset` or `back_off` the interval itself, it's backed off on errors
+    async fn poll(&mut self, first: bool) -> Result<(), Self::Error>;
+    /// Takes next item fetched by [`Poller::poll`]
+    fn pending(&mut self) -> Option<Result<Self::Item, Self::Error>>;
+    fn interval(&mut self) -> &mut AdaptiveInterval;
+}
+
+/// Yields pending items, polling again once they run out
+/// - First poll happens right away, later ones wait for the interval
+/// - Errors are yielded without stopping the stream
+pub(crate) fn watch_stream<P: Poller>(poller: P) -> impl Stream<Item = Result<P::Item, P::Error>> + Send {
+    futures_util::stream::unfold((poller, true), |(mut poller, mut first)| async move {
+        loop {
+            if let Some(item) = poller.pending() {
+                return Some((item, (poller, first)))
+            }
+            if !first {
+                poller.interval().wait().await;
+            }
+            let result = poller.poll(first).await;
+            first = false;
+            if let Err(error) = result {
+                poller.interval().back_off();
+                return Some((Err(error), (poller, first)))
+            }
+        }
+    })
+}
+// endregion: Poller
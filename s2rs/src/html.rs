@@ -1,4 +1,4 @@
-use html_parser::{Element, Dom};
+use html_parser::{Element, Dom, Node};
 
 // region: ElementUtils
 pub trait ElementUtils {
@@ -7,7 +7,10 @@ pub trait ElementUtils {
     fn child_by_attribute(&self, name: &str, value: &str) -> Option<&Element>;
     fn child_by_id(&self, name: &str) -> Option<&Element>;
     fn get_attribute(&self, name: &str) -> Option<String>;
-    fn get_text(&self) -> Option<&str>;
+    /// All text inside of the element with HTML entities decoded
+    fn text_content(&self) -> String;
+    /// All elements nested in the element, in document order
+    fn descendants(&self) -> Vec<&Element>;
 }
 
 impl ElementUtils for Element {
@@ -72,15 +75,86 @@ impl ElementUtils for Element {
         self.attributes.get(name)?.clone()
     }
 
-    fn get_text(&self) -> Option<&str> {
-        self.children.first()?.text()
+    fn text_content(&self) -> String {
+        let mut result = String::new();
+        for child in &self.children {
+            match child {
+                Node::Text(text) => result.push_str(&decode_entities(text)),
+                Node::Element(element) => result.push_str(&element.text_content()),
+                Node::Comment(_) => {}
+            }
+        }
+        result
+    }
+
+    fn descendants(&self) -> Vec<&Element> {
+        let mut result = Vec::new();
+        collect_descendants(&self.children, &mut result);
+        result
+    }
+}
+
+fn collect_descendants<'a>(nodes: &'a [Node], result: &mut Vec<&'a Element>) {
+    for element in nodes.iter().filter_map(Node::element) {
+        result.push(element);
+        collect_descendants(&element.children, result);
     }
 }
 // endregion: ElementUtils
 
+// region: entities
+/// Decodes HTML character references such as `&amp;` or `&#39;`
+/// - Unknown references are kept as they are
+pub fn decode_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';')
+        .filter(|end| *end <= 10)
+        .and_then(|end| Some((decode_entity(&rest[1..end])?, end)));
+        match decoded {
+            Some((character, end)) => {
+                result.push(character);
+                rest = &rest[end + 1..];
+            },
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let code = name.strip_prefix('#')?;
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?
+            };
+            char::from_u32(code)
+        }
+    }
+}
+// endregion: entities
+
 // region: DomUtils
 pub trait DomUtils {
+    #[allow(unused)]
     fn child_by_name(&self, name: &str) -> Option<&Element>;
+    /// All elements of the document, in document order
+    fn descendants(&self) -> Vec<&Element>;
 }
 
 impl DomUtils for Dom {
@@ -94,5 +168,11 @@ impl DomUtils for Dom {
         }
         None
     }
+
+    fn descendants(&self) -> Vec<&Element> {
+        let mut result = Vec::new();
+        collect_descendants(&self.children, &mut result);
+        result
+    }
 }
 // endregion: DomUtils
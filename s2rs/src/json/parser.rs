@@ -14,7 +14,7 @@ pub struct ExpectedError {
     pub expected: ExpectedErrorVariant
 }
 
-#[cfg_attr(feature = "ser", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[derive(Debug, Clone, Copy)]
 pub enum ExpectedErrorVariant {
     Bool,
@@ -88,7 +88,7 @@ impl<T> TryAs<Vec<T>, ExpectedError> for Parser where Self: TryAs<T, ExpectedErr
 }
 // endregion: ParserAs
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct Parser {
     value: Value
 }
@@ -178,7 +178,6 @@ impl Parser {
         Ok(result)
     }
 
-    #[allow(unused)]
     pub fn value(&self) -> &Value {
         &self.value
     }
@@ -1,5 +1,7 @@
+use std::str::FromStr;
+#[cfg(feature = "serde")] use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Language {
     Abkhazian,
     Afrikaans,
@@ -78,16 +80,219 @@ pub enum Language {
     Ukrainian,
     SimplifiedChinese,
     TraditionalChinese,
-    Custom(&'static str)
+    /// Any other locale code, e.g. one Scratch added after this enum
+    Custom(String)
 }
 
 impl Language {
+    /// Every language except [`Language::Custom`]
+    pub const ALL: [Self; 77] = [
+        Self::Abkhazian,
+        Self::Afrikaans,
+        Self::Arabic,
+        Self::Amharic,
+        Self::Aragonese,
+        Self::Asturian,
+        Self::Azerbaijani,
+        Self::Indonesian,
+        Self::Bengali,
+        Self::Belarusian,
+        Self::Bulgarian,
+        Self::Catalan,
+        Self::Czech,
+        Self::Welsh,
+        Self::Danish,
+        Self::German,
+        Self::Estonian,
+        Self::Greek,
+        Self::English,
+        Self::Spanish,
+        Self::SpanishLatinAmerica,
+        Self::Esperanto,
+        Self::Basque,
+        Self::Persian,
+        Self::Filipino,
+        Self::French,
+        Self::Frisian,
+        Self::Irish,
+        Self::Gaelish,
+        Self::Korean,
+        Self::Hausa,
+        Self::Armenian,
+        Self::Croatian,
+        Self::Xhosa,
+        Self::Zulu,
+        Self::Icelandic,
+        Self::Italian,
+        Self::Kannada,
+        Self::Kazakh,
+        Self::Quechua,
+        Self::Swahili,
+        Self::HatianCreole,
+        Self::Kurdish,
+        Self::CentralKurdish,
+        Self::Latvian,
+        Self::Lithuanian,
+        Self::Hungarian,
+        Self::Maori,
+        Self::Mongolian,
+        Self::Dutch,
+        Self::Japanese,
+        Self::JapaneseWithoutKanji,
+        Self::NorwegianBokmal,
+        Self::NorwegianNynorsk,
+        Self::Occitan,
+        Self::Oriya,
+        Self::Uzbek,
+        Self::Thai,
+        Self::CentralKhmer,
+        Self::Polish,
+        Self::Portuguese,
+        Self::PortugueseBrazil,
+        Self::RapaNui,
+        Self::Romanian,
+        Self::Russian,
+        Self::NorthernSotho,
+        Self::Tswana,
+        Self::Slovak,
+        Self::Slovenian,
+        Self::Serbian,
+        Self::Finish,
+        Self::Swedish,
+        Self::Vietnamese,
+        Self::Turkish,
+        Self::Ukrainian,
+        Self::SimplifiedChinese,
+        Self::TraditionalChinese,
+    ];
+
+    /// Scratch locale code, such as `en` or `pt-br`
     pub fn as_code(&self) -> &str {
         match self {
+            Self::Abkhazian => "ab",
+            Self::Afrikaans => "af",
+            Self::Arabic => "ar",
+            Self::Amharic => "am",
+            Self::Aragonese => "an",
+            Self::Asturian => "ast",
+            Self::Azerbaijani => "az",
+            Self::Indonesian => "id",
+            Self::Bengali => "bn",
+            Self::Belarusian => "be",
+            Self::Bulgarian => "bg",
+            Self::Catalan => "ca",
+            Self::Czech => "cs",
+            Self::Welsh => "cy",
+            Self::Danish => "da",
+            Self::German => "de",
+            Self::Estonian => "et",
+            Self::Greek => "el",
             Self::English => "en",
+            Self::Spanish => "es",
+            Self::SpanishLatinAmerica => "es-419",
+            Self::Esperanto => "eo",
+            Self::Basque => "eu",
+            Self::Persian => "fa",
+            Self::Filipino => "fil",
+            Self::French => "fr",
+            Self::Frisian => "fy",
+            Self::Irish => "ga",
+            Self::Gaelish => "gd",
+            Self::Korean => "ko",
+            Self::Hausa => "ha",
+            Self::Armenian => "hy",
+            Self::Croatian => "hr",
+            Self::Xhosa => "xh",
+            Self::Zulu => "zu",
+            Self::Icelandic => "is",
+            Self::Italian => "it",
+            Self::Kannada => "kn",
+            Self::Kazakh => "kk",
+            Self::Quechua => "qu",
+            Self::Swahili => "sw",
+            Self::HatianCreole => "ht",
+            Self::Kurdish => "ku",
+            Self::CentralKurdish => "ckb",
+            Self::Latvian => "lv",
+            Self::Lithuanian => "lt",
+            Self::Hungarian => "hu",
+            Self::Maori => "mi",
+            Self::Mongolian => "mn",
+            Self::Dutch => "nl",
+            Self::Japanese => "ja",
+            Self::JapaneseWithoutKanji => "ja-Hira",
+            Self::NorwegianBokmal => "nb",
+            Self::NorwegianNynorsk => "nn",
+            Self::Occitan => "oc",
+            Self::Oriya => "or",
+            Self::Uzbek => "uz",
+            Self::Thai => "th",
+            Self::CentralKhmer => "km",
+            Self::Polish => "pl",
+            Self::Portuguese => "pt",
+            Self::PortugueseBrazil => "pt-br",
+            Self::RapaNui => "rap",
+            Self::Romanian => "ro",
             Self::Russian => "ru",
-            Self::Custom(name) => name,
-            _ => panic!("Your language `{self:?}` is not yet supported, use Language::Custom(\"...\")")
+            Self::NorthernSotho => "nso",
+            Self::Tswana => "tn",
+            Self::Slovak => "sk",
+            Self::Slovenian => "sl",
+            Self::Serbian => "sr",
+            Self::Finish => "fi",
+            Self::Swedish => "sv",
+            Self::Vietnamese => "vi",
+            Self::Turkish => "tr",
+            Self::Ukrainian => "uk",
+            Self::SimplifiedChinese => "zh-cn",
+            Self::TraditionalChinese => "zh-tw",
+            Self::Custom(code) => code,
         }
     }
-}
\ No newline at end of file
+
+    /// Codes are compared case-insensitively, unknown ones are kept as [`Language::Custom`]
+    pub fn from_code(code: &str) -> Self {
+        Self::ALL.into_iter()
+        .find(|language| language.as_code().eq_ignore_ascii_case(code))
+        .unwrap_or_else(|| Self::Custom(code.to_owned()))
+    }
+}
+
+impl FromStr for Language {
+    type Err = std::convert::Infallible;
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from_code(code))
+    }
+}
+
+impl From<&str> for Language {
+    fn from(code: &str) -> Self {
+        Self::from_code(code)
+    }
+}
+
+impl From<String> for Language {
+    fn from(code: String) -> Self {
+        Self::from_code(&code)
+    }
+}
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_code())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Language {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.as_code())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Language {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self::from_code(&String::deserialize(deserializer)?))
+    }
+}
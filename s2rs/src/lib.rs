@@ -3,13 +3,19 @@ pub use api::Api;
 pub use session::Session;
 // pub use entities::*; // TO BE ENABLED # DISABLE IS DEV ONLY
 pub use language::Language;
+pub use country::Country;
 pub use cursor::Cursor;
+pub use date::Date;
 
 pub mod api;
 pub mod session;
 pub mod entities;
 pub mod cursor;
+pub mod date;
 pub mod language;
+pub mod country;
+pub mod bbcode;
+#[cfg(feature = "bot")] pub mod bot;
 mod utils;
 mod cookies;
 mod headers;
@@ -1,7 +1,8 @@
 use std::sync::Arc;
 use s2rs_derive::Forwarder;
 
-use crate::{api::{Api, Tokens, self}, entities::{User, Project, Studio, Me, ForumTopic, ForumPost}, utils::into_arc::IntoArc};
+use crate::{api::{Api, Tokens, Cache, self}, entities::{User, Project, Studio, Me, ForumTopic, ForumPost}, utils::into_arc::IntoArc};
+#[cfg(all(feature = "watch", feature = "rss"))] use crate::entities::{forum_watch, ForumWatchOptions, ForumWatchPost, ForumWatchError};
 
 pub struct ExtensionPipe {
     pub me: Arc<Me>,
@@ -73,6 +74,15 @@ impl Session {
         })?)
     }
 
+    /// Same session with api responses going through `cache`
+    pub fn with_cache(&self, cache: Arc<Cache>) -> Arc<Self> {
+        let api = self.api.with_cache(cache);
+        Arc::new(Self {
+            me: Me::with_this(User::new(self.me.name.clone(), api.clone()), api.clone()),
+            api
+        })
+    }
+
     pub fn user(&self, name: impl IntoArc<String>) -> Arc<User> {
         User::new(name, self.api.clone())
     }
@@ -93,6 +103,34 @@ impl Session {
         ForumPost::new(id, self.api.clone())
     }
 
+    /// Watch forum topics for new posts
+    /// - `Requires crate features: 'watch', 'rss'`
+    /// - Topics are read through RSS, which only has the latest posts, so posts are missed if lots of them are made between polls
+    /// - Errors are yielded without stopping the stream
+    /// # Examples
+    /// ```
+    /// # tokio_test::block_on(async {
+    /// # use s2rs::session::Session;
+    /// use std::sync::Arc;
+    /// use futures_util::StreamExt;
+    /// use s2rs::entities::{ForumWatchOptions, ForumWatchFile};
+    /// # let session = Session::new("YourUsername");
+    /// let file = ForumWatchFile::new("forum.json");
+    /// let mut options = ForumWatchOptions::new(vec![6369, 105500]);
+    /// options.seen = file.load().unwrap_or_default();
+    /// options.checkpoint = Some(Arc::new(file));
+    /// let mut posts = Box::pin(session.watch_forum(options));
+    /// while let Some(post) = posts.next().await {
+    ///     let post = post.unwrap();
+    ///     dbg![ &post.topic_title, &post.post.author.name ];
+    /// }
+    /// # })
+    /// ```
+    #[cfg(all(feature = "watch", feature = "rss"))]
+    pub fn watch_forum(&self, options: ForumWatchOptions) -> impl futures_util::Stream<Item = Result<ForumWatchPost, ForumWatchError>> + Send {
+        forum_watch::watch(self.api.clone(), options)
+    }
+
     pub fn me(&self) -> Arc<Me> {
         self.me.clone()
     }
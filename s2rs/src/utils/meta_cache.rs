@@ -0,0 +1,47 @@
+use std::sync::{Mutex, MutexGuard};
+
+/// Lazily filled metadata slot of an entity handle
+/// - Stores raw api data, so cached entities don't keep their handle alive
+#[derive(Debug)]
+pub struct MetaCache<T> {
+    data: Mutex<Option<T>>
+}
+
+impl<T> MetaCache<T> {
+    pub fn new(data: Option<T>) -> Self {
+        Self {
+            data: Mutex::new(data)
+        }
+    }
+
+    pub fn set(&self, data: T) {
+        *self.lock() = Some(data);
+    }
+
+    pub fn clear(&self) {
+        *self.lock() = None;
+    }
+
+    /// Cached data is still usable after a panic while it was locked
+    fn lock(&self) -> MutexGuard<'_, Option<T>> {
+        self.data.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<T: Clone> MetaCache<T> {
+    pub fn get(&self) -> Option<T> {
+        self.lock().clone()
+    }
+}
+
+impl<T> Default for MetaCache<T> {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl<T: Clone> Clone for MetaCache<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.get())
+    }
+}
@@ -3,5 +3,6 @@ pub mod serde;
 pub mod string;
 pub mod try_as;
 pub mod into_arc;
+pub mod meta_cache;
 
 pub use try_as::TryAs;
\ No newline at end of file